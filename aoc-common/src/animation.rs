@@ -0,0 +1,268 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufRead, BufWriter, IsTerminal, Write},
+    path::PathBuf,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+}
+
+impl Color {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::Gray => 90,
+        }
+    }
+}
+
+/// Tiles that can be drawn in an animation frame.
+pub trait Paint: Display {
+    fn color(&self) -> Option<Color> {
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Frames per second, `0` to not wait between frames at all.
+    pub fps: f64,
+    pub colors: bool,
+    /// Start paused, showing the next frame whenever Enter is pressed.
+    pub paused: bool,
+    /// Show the frames in the terminal, instead of only recording them.
+    pub display: bool,
+    /// Also write every frame as plain text to this file.
+    pub record: Option<PathBuf>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            fps: 20.0,
+            colors: true,
+            paused: false,
+            display: true,
+            record: None,
+        }
+    }
+}
+
+enum Control {
+    Step,
+    Continue,
+    Quit,
+}
+
+/// Renders grids frame by frame, for days that can show how they get to their answer.
+///
+/// When stdin is a terminal, pressing Enter pauses or steps a single frame, `c` and Enter
+/// continues and `q` and Enter stops showing frames.
+pub struct Animation {
+    options: Options,
+    recorder: Option<BufWriter<File>>,
+    controls: Option<Receiver<Control>>,
+    paused: bool,
+    stopped: bool,
+    frames: usize,
+    last_frame: Option<Instant>,
+}
+
+impl Animation {
+    pub fn new(options: Options) -> io::Result<Self> {
+        let recorder = match &options.record {
+            Some(path) => Some(BufWriter::new(File::create(path)?)),
+            None => None,
+        };
+        let controls = (options.display && io::stdin().is_terminal()).then(|| {
+            let (sender, receiver) = mpsc::channel();
+            thread::spawn(move || {
+                for line in io::stdin().lock().lines() {
+                    let control = match line.as_deref().map(str::trim) {
+                        Ok("") => Control::Step,
+                        Ok("c") => Control::Continue,
+                        Ok("q") | Err(_) => Control::Quit,
+                        Ok(_) => continue,
+                    };
+                    if sender.send(control).is_err() {
+                        break;
+                    }
+                }
+            });
+            receiver
+        });
+        Ok(Self {
+            paused: options.paused && controls.is_some(),
+            options,
+            recorder,
+            controls,
+            stopped: false,
+            frames: 0,
+            last_frame: None,
+        })
+    }
+
+    /// How many frames have been shown so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Whether the viewer quit, in which case further frames are skipped.
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    pub fn frame<T: Paint>(&mut self, grid: &Grid<T>) {
+        if self.stopped {
+            return;
+        }
+        self.frames += 1;
+        if let Some(recorder) = &mut self.recorder {
+            let written = write!(recorder, "frame {}\n{}\n", self.frames, render(grid, false));
+            if written.is_err() {
+                self.recorder = None;
+            }
+        }
+        if self.options.display {
+            let help = match self.controls {
+                Some(_) => "  (Enter: pause/step, c: continue, q: quit)",
+                None => "",
+            };
+            // clear the screen and move the cursor to its top left corner
+            print!(
+                "\x1b[2J\x1b[H{}\nframe {}{help}\n",
+                render(grid, self.options.colors),
+                self.frames
+            );
+            let _ = io::stdout().flush();
+        }
+        self.wait();
+    }
+
+    /// Waits for the next frame to be due or, when paused, for the viewer to step.
+    fn wait(&mut self) {
+        let frame_time = if self.options.display && self.options.fps > 0.0 {
+            Duration::from_secs_f64(1.0 / self.options.fps)
+        } else {
+            Duration::ZERO
+        };
+        let due = self.last_frame.unwrap_or_else(Instant::now) + frame_time;
+        loop {
+            let control = match &self.controls {
+                Some(controls) if self.paused => {
+                    controls.recv().map_err(|_| RecvTimeoutError::Disconnected)
+                }
+                Some(controls) => {
+                    controls.recv_timeout(due.saturating_duration_since(Instant::now()))
+                }
+                None => Err(RecvTimeoutError::Disconnected),
+            };
+            match control {
+                Ok(Control::Step) if self.paused => break,
+                Ok(Control::Step) => self.paused = true,
+                Ok(Control::Continue) => {
+                    self.paused = false;
+                    break;
+                }
+                Ok(Control::Quit) => {
+                    self.stopped = true;
+                    break;
+                }
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => {
+                    self.controls = None;
+                    self.paused = false;
+                    thread::sleep(due.saturating_duration_since(Instant::now()));
+                    break;
+                }
+            }
+        }
+        self.last_frame = Some(Instant::now());
+    }
+}
+
+impl Drop for Animation {
+    fn drop(&mut self) {
+        if let Some(recorder) = &mut self.recorder {
+            let _ = recorder.flush();
+        }
+    }
+}
+
+fn render<T: Paint>(grid: &Grid<T>, colors: bool) -> String {
+    let mut res = String::new();
+    for row in grid.rows() {
+        for cell in row {
+            match cell.color().filter(|_| colors) {
+                Some(color) => res += &format!("\x1b[{}m{cell}\x1b[0m", color.ansi_code()),
+                None => res += &cell.to_string(),
+            }
+        }
+        res.push('\n');
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Cell(char);
+
+    impl Display for Cell {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl Paint for Cell {
+        fn color(&self) -> Option<Color> {
+            (self.0 == '#').then_some(Color::Red)
+        }
+    }
+
+    #[test]
+    fn render_with_and_without_colors() {
+        let grid = Grid::parse(".#\n..\n", "a cell", |c| Some(Cell(c))).unwrap();
+        assert_eq!(render(&grid, false), ".#\n..\n");
+        assert_eq!(render(&grid, true), ".\x1b[31m#\x1b[0m\n..\n");
+    }
+
+    #[test]
+    fn record_frames() {
+        let path = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let grid = Grid::parse("#.\n", "a cell", |c| Some(Cell(c))).unwrap();
+        let mut animation = Animation::new(Options {
+            display: false,
+            record: Some(path.clone()),
+            ..Options::default()
+        })
+        .unwrap();
+        animation.frame(&grid);
+        animation.frame(&grid);
+        assert_eq!(animation.frames(), 2);
+        drop(animation);
+        let recorded = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(recorded, "frame 1\n#.\n\nframe 2\n#.\n\n");
+        std::fs::remove_file(path).unwrap();
+    }
+}
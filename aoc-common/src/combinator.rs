@@ -0,0 +1,575 @@
+//! Parser combinators for the puzzle input formats.
+//!
+//! A parser takes the [`Input`] still to be read and returns what it parsed together with the
+//! input after it. Alternatives and repetitions only backtrack over parsers that failed without
+//! reading anything, so an error deep inside a line is reported where it happened instead of
+//! where the line started.
+
+use std::str::FromStr;
+
+use crate::{
+    grid::Grid,
+    parse::{Found, ParseError},
+};
+
+/// The part of the input still to be parsed, remembering the whole input to locate errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Input<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+/// Why a parser failed, located by the byte offset into the input it failed at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    offset: usize,
+    expected: String,
+}
+
+pub type PResult<'a, T> = Result<(T, Input<'a>), Failure>;
+
+impl<'a> Input<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self { source, offset: 0 }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.offset..]
+    }
+
+    /// Splits off the next `len` bytes.
+    pub fn take(self, len: usize) -> (&'a str, Self) {
+        let taken = &self.rest()[..len];
+        let rest = Self {
+            offset: self.offset + len,
+            ..self
+        };
+        (taken, rest)
+    }
+
+    /// A failure at the start of the rest of the input.
+    pub fn fail(&self, expected: impl Into<String>) -> Failure {
+        Failure {
+            offset: self.offset,
+            expected: expected.into(),
+        }
+    }
+
+    /// An error at the start of the rest of the input, for checks once everything is parsed,
+    /// like a name that is used but never defined.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.locate(self.fail(expected))
+    }
+
+    fn locate(&self, failure: Failure) -> ParseError {
+        let before = &self.source[..failure.offset];
+        let rest = &self.source[failure.offset..];
+        let found = match rest.chars().next() {
+            None => Found::EndOfInput,
+            Some('\n' | '\r') => Found::EndOfLine,
+            Some(c) if c.is_alphanumeric() => {
+                let end = rest
+                    .find(|c: char| !c.is_alphanumeric())
+                    .unwrap_or(rest.len());
+                Found::Token(rest[..end].to_string())
+            }
+            Some(c) => Found::Token(c.to_string()),
+        };
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before
+                .rsplit('\n')
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .count()
+                + 1,
+            expected: failure.expected,
+            found,
+        }
+    }
+}
+
+pub trait Parser<'a, T> {
+    fn parse_next(&self, input: Input<'a>) -> PResult<'a, T>;
+
+    /// Parses the whole input, which may end in whitespace.
+    fn parse_all(&self, input_str: &'a str) -> Result<T, ParseError> {
+        let input = Input::new(input_str);
+        let (value, rest) = self.parse_next(input).map_err(|f| input.locate(f))?;
+        let trailing = rest.rest().len() - rest.rest().trim_start().len();
+        let (_, end) = rest.take(trailing);
+        if !end.rest().is_empty() {
+            let expected = match rest.rest().starts_with(['\n', '\r']) {
+                true => "end of input",
+                false => "end of line",
+            };
+            return Err(input.locate(end.fail(expected)));
+        }
+        Ok(value)
+    }
+
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| {
+            let (value, rest) = self.parse_next(input)?;
+            Ok((f(value), rest))
+        }
+    }
+
+    /// Converts the value, failing with `expected` at its start where `f` returns `None`.
+    fn try_map<U>(self, expected: &'static str, f: impl Fn(T) -> Option<U>) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| {
+            let (value, rest) = self.parse_next(input)?;
+            f(value)
+                .map(|value| (value, rest))
+                .ok_or_else(|| input.fail(expected))
+        }
+    }
+
+    /// Keeps the input the value started at, to report errors about it once everything is parsed.
+    fn located(self) -> impl Parser<'a, (T, Input<'a>)>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| {
+            let (value, rest) = self.parse_next(input)?;
+            Ok(((value, input), rest))
+        }
+    }
+
+    /// Parses `next` after this parser, keeping both values.
+    fn then<U>(self, next: impl Parser<'a, U>) -> impl Parser<'a, (T, U)>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| {
+            let (first, rest) = self.parse_next(input)?;
+            let (second, rest) = next.parse_next(rest)?;
+            Ok(((first, second), rest))
+        }
+    }
+
+    /// Parses `next` after this parser, keeping only the value of `next`.
+    fn ignore_then<U>(self, next: impl Parser<'a, U>) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        self.then(next).map(|(_, second)| second)
+    }
+
+    /// Parses `next` after this parser, keeping only the value of this one.
+    fn then_ignore<U>(self, next: impl Parser<'a, U>) -> impl Parser<'a, T>
+    where
+        Self: Sized,
+    {
+        self.then(next).map(|(first, _)| first)
+    }
+
+    /// Tries `other` where this parser fails without reading anything.
+    fn or(self, other: impl Parser<'a, T>) -> impl Parser<'a, T>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| match self.parse_next(input) {
+            Err(failure) if failure.offset == input.offset => {
+                other.parse_next(input).map_err(|other| match other.offset {
+                    offset if offset == input.offset => {
+                        input.fail(format!("{} or {}", failure.expected, other.expected))
+                    }
+                    _ => other,
+                })
+            }
+            res => res,
+        }
+    }
+
+    /// `None` where this parser fails without reading anything.
+    fn opt(self) -> impl Parser<'a, Option<T>>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| match self.parse_next(input) {
+            Ok((value, rest)) => Ok((Some(value), rest)),
+            Err(failure) if failure.offset == input.offset => Ok((None, input)),
+            Err(failure) => Err(failure),
+        }
+    }
+
+    /// Describes what this parser expects when it fails without reading anything.
+    fn label(self, expected: &'static str) -> impl Parser<'a, T>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| {
+            self.parse_next(input)
+                .map_err(|failure| match failure.offset {
+                    offset if offset == input.offset => input.fail(expected),
+                    _ => failure,
+                })
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(Input<'a>) -> PResult<'a, T>,
+{
+    fn parse_next(&self, input: Input<'a>) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+pub fn tag<'a>(tag: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| match input.rest().starts_with(tag) {
+        true => Ok(input.take(tag.len())),
+        false => Err(input.fail(format!("`{}`", tag.escape_debug()))),
+    }
+}
+
+/// One of the characters in `chars`.
+pub fn one_of<'a>(chars: &'static str) -> impl Parser<'a, char> {
+    move |input: Input<'a>| match input.rest().chars().next() {
+        Some(c) if chars.contains(c) => Ok((c, input.take(c.len_utf8()).1)),
+        _ => {
+            let options: Vec<String> = chars.chars().map(|c| format!("`{c}`")).collect();
+            Err(input.fail(options.join(" or ")))
+        }
+    }
+}
+
+/// The longest non-empty run of characters matching `predicate`.
+pub fn take_while1<'a>(
+    expected: &'static str,
+    predicate: impl Fn(char) -> bool,
+) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        match rest.find(|c: char| !predicate(c)).unwrap_or(rest.len()) {
+            0 => Err(input.fail(expected)),
+            len => Ok(input.take(len)),
+        }
+    }
+}
+
+/// A run of letters and digits, like the names of nodes and workflows.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    take_while1("a name", char::is_alphanumeric)
+}
+
+/// Spaces and tabs, possibly none.
+pub fn spaces<'a>() -> impl Parser<'a, ()> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let len = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+        Ok(((), input.take(len).1))
+    }
+}
+
+pub fn newline<'a>() -> impl Parser<'a, ()> {
+    tag("\n").or(tag("\r\n")).label("end of line").map(|_| ())
+}
+
+fn number<'a, T: FromStr>(
+    expected: &'static str,
+    digits: impl Parser<'a, &'a str>,
+) -> impl Parser<'a, T> {
+    digits
+        .try_map(expected, |digits| digits.parse().ok())
+        .label(expected)
+}
+
+/// An unsigned integer, failing where it does not fit a `T`.
+pub fn uint<'a, T: FromStr>() -> impl Parser<'a, T> {
+    number(
+        "an unsigned integer",
+        take_while1("", |c| c.is_ascii_digit()),
+    )
+}
+
+/// An integer with an optional leading `-`, failing where it does not fit a `T`.
+pub fn int<'a, T: FromStr>() -> impl Parser<'a, T> {
+    let digits = move |input: Input<'a>| {
+        let sign = usize::from(input.rest().starts_with('-'));
+        let len = input.rest()[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.rest().len() - sign);
+        match len {
+            0 => Err(input.fail("")),
+            len => Ok(input.take(sign + len)),
+        }
+    };
+    number("an integer", digits)
+}
+
+/// `open`, `inner` and `close`, keeping the value of `inner`.
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    inner: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, T> {
+    open.ignore_then(inner).then_ignore(close)
+}
+
+/// One or more `item`s with a `separator` between each two of them.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    repeated(item, separator, false)
+}
+
+/// One or more `item`s with a `separator` between each two of them. With `backtrack`, it ends
+/// before a separator that is not followed by an item, instead of failing after it.
+fn repeated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+    backtrack: bool,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: Input<'a>| {
+        let (first, mut rest) = item.parse_next(input)?;
+        let mut items = vec![first];
+        loop {
+            let Ok((_, after_separator)) = separator.parse_next(rest) else {
+                break;
+            };
+            match item.parse_next(after_separator) {
+                Ok((next, after_item)) => {
+                    items.push(next);
+                    rest = after_item;
+                }
+                Err(failure) if backtrack && failure.offset == after_separator.offset => break,
+                Err(failure) => return Err(failure),
+            }
+        }
+        Ok((items, rest))
+    }
+}
+
+/// One or more `item`s separated by spaces, like `41 48 83  6 17`. Spaces after the last item
+/// are left to the parser after the list.
+pub fn list<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    repeated(
+        item,
+        take_while1("a space", |c| matches!(c, ' ' | '\t')),
+        true,
+    )
+}
+
+/// `key: value`, with any number of spaces after the colon.
+pub fn header<'a, K, V>(
+    key: impl Parser<'a, K>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    key.then_ignore(tag(":")).then_ignore(spaces()).then(value)
+}
+
+/// `label = value` or `label=value`.
+pub fn labelled<'a, L, V>(
+    label: impl Parser<'a, L>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (L, V)> {
+    label
+        .then_ignore(spaces())
+        .then_ignore(tag("="))
+        .then_ignore(spaces())
+        .then(value)
+}
+
+/// A record of labelled fields in braces, like `{x=787,m=2655}`.
+pub fn record<'a, L, V>(
+    label: impl Parser<'a, L>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, Vec<(L, V)>> {
+    delimited(
+        tag("{"),
+        separated(labelled(label, value), tag(",").then(spaces())),
+        tag("}"),
+    )
+}
+
+/// Whether only spaces are left before the end of the line.
+fn at_blank_line(input: Input) -> bool {
+    let rest = input.rest().trim_start_matches([' ', '\t']);
+    rest.is_empty() || rest.starts_with(['\n', '\r'])
+}
+
+/// The end of a line that is followed by another one which is not blank.
+fn line_break<'a>() -> impl Parser<'a, ()> {
+    move |input: Input<'a>| {
+        let ((), rest) = newline().parse_next(input)?;
+        match at_blank_line(rest) {
+            true => Err(input.fail("another line")),
+            false => Ok(((), rest)),
+        }
+    }
+}
+
+/// One `item` per line, up to a blank line or the end of the input.
+pub fn lines_of<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    repeated(item, line_break(), false)
+}
+
+/// Like [`lines_of`] for the lines after the current one, of which there may be none, like the
+/// lines below a header.
+pub fn following_lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: Input<'a>| {
+        let mut items = vec![];
+        let mut rest = input;
+        while let Ok(((), next)) = line_break().parse_next(rest) {
+            let (value, after) = item.parse_next(next)?;
+            items.push(value);
+            rest = after;
+        }
+        Ok((items, rest))
+    }
+}
+
+/// The end of a line followed by one or more blank lines, and then more input.
+pub fn blank_lines<'a>() -> impl Parser<'a, ()> {
+    move |input: Input<'a>| {
+        let ((), mut rest) = newline().parse_next(input)?;
+        let mut blank = 0;
+        while let Ok(((), next)) = spaces().ignore_then(newline()).parse_next(rest) {
+            rest = next;
+            blank += 1;
+        }
+        match blank > 0 && !rest.rest().trim().is_empty() {
+            true => Ok(((), rest)),
+            false => Err(input.fail("a blank line")),
+        }
+    }
+}
+
+/// Blocks separated by one or more blank lines.
+pub fn blocks_of<'a, T>(block: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    repeated(block, blank_lines(), false)
+}
+
+/// A rectangle of characters, one tile each, ending at a blank line or the end of the input.
+pub fn grid<'a, T>(
+    expected: &'static str,
+    tile: impl Fn(char) -> Option<T>,
+) -> impl Parser<'a, Grid<T>> {
+    move |input: Input<'a>| {
+        if at_blank_line(input) {
+            return Err(input.fail(expected));
+        }
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        let mut rest = input;
+        loop {
+            let line = &rest.rest()[..rest.rest().find(['\r', '\n']).unwrap_or(rest.rest().len())];
+            let row_width = line.chars().count();
+            if let Some(width) = width.filter(|width| *width != row_width) {
+                // points at the first tile too many, or at the end of a row that is too short
+                let end = line
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.len(), |(idx, _)| idx);
+                return Err(rest.take(end).1.fail(format!("a row of {width} tiles")));
+            }
+            width = Some(row_width);
+            for c in line.chars() {
+                cells.push(tile(c).ok_or_else(|| rest.fail(expected))?);
+                rest = rest.take(c.len_utf8()).1;
+            }
+            height += 1;
+            match newline().parse_next(rest) {
+                Ok(((), next)) if !at_blank_line(next) => rest = next,
+                _ => break,
+            }
+        }
+        Ok((Grid::new(width.unwrap_or(0), height, cells), rest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(uint::<u32>().parse_all("4294967295"), Ok(u32::MAX));
+        assert_eq!(int::<i64>().parse_all("-19"), Ok(-19));
+        let error = uint::<u8>().parse_all("256").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected an unsigned integer, found `256`"
+        );
+        assert!(uint::<u8>().parse_all("-1").is_err());
+        assert!(int::<i8>().parse_all("-").is_err());
+    }
+
+    #[test]
+    fn headers_and_lists() {
+        let card = header(
+            tag("Card").then(spaces()).ignore_then(uint::<u8>()),
+            list(uint()),
+        )
+        .then_ignore(tag(" |"));
+        assert_eq!(
+            lines_of(card).parse_all("Card 1: 41 48  6 |\nCard  2:  1 |\n"),
+            Ok(vec![(1, vec![41, 48, 6]), (2, vec![1])])
+        );
+        let error = header(word(), list(int::<i32>()))
+            .parse_all("seeds: 79 x")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 11: expected end of line, found `x`"
+        );
+    }
+
+    #[test]
+    fn records_and_blocks() {
+        let part = record(one_of("xmas"), uint::<u32>());
+        let blocks = blocks_of(lines_of(part)).parse_all("{x=1,m=2}\n\n\n{a=3}\n{s=4}");
+        assert_eq!(
+            blocks,
+            Ok(vec![
+                vec![vec![('x', 1), ('m', 2)]],
+                vec![vec![('a', 3)], vec![('s', 4)]]
+            ])
+        );
+        let node = labelled(word(), delimited(tag("("), word(), tag(")")));
+        assert_eq!(node.parse_all("AAA = (BBB)"), Ok(("AAA", "BBB")));
+        let error = lines_of(node)
+            .parse_all("AAA = (BBB)\nCCC = DDD)")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: expected `(`, found `DDD`"
+        );
+    }
+
+    #[test]
+    fn grids() {
+        let tile = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        let grids = blocks_of(grid("`#` or `.`", tile))
+            .parse_all("#.\n.#\n\n##\n")
+            .unwrap();
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[0].cells(), &[true, false, false, true]);
+        let error = grid("`#` or `.`", tile).parse_all("#.\n#\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected a row of 2 tiles, found end of line"
+        );
+        let error = grid("`#` or `.`", tile).parse_all("#.\n#x\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected `#` or `.`, found `x`"
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+/// Where a sequence of states starts repeating, counted in steps from the initial state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step that is part of the cycle.
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step that reaches the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// How [`find_cycle`] recognises a state it has seen before.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Floyd's tortoise and hare, keeping only two states around.
+    Floyd,
+    /// Brent's algorithm, keeping only two states around and taking fewer steps than Floyd.
+    #[default]
+    Brent,
+    /// Remembers every state, taking each step only once.
+    Hash,
+    /// Like [`Strategy::Hash`], but only remembers a 64-bit hash of each state, so a hash
+    /// collision could report a wrong cycle.
+    HashOnly,
+}
+
+/// Finds the cycle that repeatedly applying `step` to `initial` ends up in.
+///
+/// Never returns if the states don't repeat.
+pub fn find_cycle<S, F>(initial: &S, step: F, strategy: Strategy) -> Cycle
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    match strategy {
+        Strategy::Floyd => floyd(initial, step),
+        Strategy::Brent => brent(initial, step),
+        Strategy::Hash => hashed(initial, step, S::clone),
+        Strategy::HashOnly => hashed(initial, step, hash_of),
+    }
+}
+
+/// The state after applying `step` to `initial` `n` times, skipping over whole cycles.
+pub fn nth_state<S, F>(initial: &S, mut step: F, n: usize, strategy: Strategy) -> S
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let cycle = find_cycle(initial, &mut step, strategy);
+    let mut state = initial.clone();
+    for _ in 0..cycle.reduce(n) {
+        state = step(&state);
+    }
+    state
+}
+
+fn floyd<S: Clone + Eq, F: FnMut(&S) -> S>(initial: &S, mut step: F) -> Cycle {
+    let mut tortoise = step(initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+    // the distance between both is now a multiple of the cycle length
+    let mut tortoise = initial.clone();
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    let mut length = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+fn brent<S: Clone + Eq, F: FnMut(&S) -> S>(initial: &S, mut step: F) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+    // walk two states `length` steps apart until they meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+fn hashed<S, K, F>(initial: &S, mut step: F, key: impl Fn(&S) -> K) -> Cycle
+where
+    S: Clone,
+    K: Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut state = initial.clone();
+    for idx in 0.. {
+        if let Some(start) = seen.insert(key(&state), idx) {
+            return Cycle {
+                start,
+                length: idx - start,
+            };
+        }
+        state = step(&state);
+    }
+    unreachable!()
+}
+
+fn hash_of<S: Hash>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STRATEGIES: [Strategy; 4] = [
+        Strategy::Floyd,
+        Strategy::Brent,
+        Strategy::Hash,
+        Strategy::HashOnly,
+    ];
+
+    fn step(x: &u32) -> u32 {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn strategies_agree() {
+        // 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+        for strategy in STRATEGIES {
+            let cycle = find_cycle(&3, step, strategy);
+            assert_eq!(
+                cycle,
+                Cycle {
+                    start: 2,
+                    length: 6
+                },
+                "{strategy:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn immediate_fixed_point() {
+        for strategy in STRATEGIES {
+            let cycle = find_cycle(&7, |x| *x, strategy);
+            assert_eq!(
+                cycle,
+                Cycle {
+                    start: 0,
+                    length: 1
+                },
+                "{strategy:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn nth_state_skips_cycles() {
+        let mut state = 3;
+        for _ in 0..1000 {
+            state = step(&state);
+        }
+        for strategy in STRATEGIES {
+            assert_eq!(nth_state(&3, step, 1000, strategy), state, "{strategy:?}");
+        }
+        assert_eq!(nth_state(&3, step, 1, Strategy::Brent), 10);
+    }
+}
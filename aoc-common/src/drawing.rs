@@ -0,0 +1,178 @@
+use crate::{
+    animation::{Color, Paint},
+    geometry::Point,
+    grid::Grid,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+    /// The background of tiles without a colour of their own.
+    pub const BACKGROUND: Self = Self(24, 24, 32);
+
+    /// Blends from `self` at `t = 0` to `other` at `t = 1`.
+    pub fn mix(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Self(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+
+    /// A heat map colour going from dark blue over red to yellow as `t` goes from 0 to 1.
+    pub fn heat(t: f64) -> Self {
+        const STOPS: [Rgb; 4] = [
+            Rgb(24, 24, 32),
+            Rgb(60, 40, 160),
+            Rgb(220, 50, 40),
+            Rgb(255, 230, 80),
+        ];
+        let t = t.clamp(0.0, 1.0) * (STOPS.len() - 1) as f64;
+        let idx = (t as usize).min(STOPS.len() - 2);
+        STOPS[idx].mix(STOPS[idx + 1], t - idx as f64)
+    }
+
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+impl From<Color> for Rgb {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Red => Rgb(220, 50, 40),
+            Color::Green => Rgb(80, 200, 90),
+            Color::Yellow => Rgb(240, 200, 60),
+            Color::Blue => Rgb(60, 110, 220),
+            Color::Magenta => Rgb(200, 80, 200),
+            Color::Cyan => Rgb(70, 200, 210),
+            Color::Gray => Rgb(110, 110, 120),
+        }
+    }
+}
+
+/// How many tiles the longer side of a polygon is rasterized to.
+pub const POLYGON_TILES: usize = 256;
+pub const POLYGON_FILL: Rgb = Rgb(60, 110, 220);
+pub const POLYGON_EDGE: Rgb = Rgb(240, 200, 60);
+
+/// A picture of a day's solution, which the runner can export as an image.
+#[derive(Debug, Clone)]
+pub enum Drawing {
+    /// One colour per tile.
+    Grid(Grid<Rgb>),
+    /// Grids shown one after another, like the frames of an [`Animation`].
+    ///
+    /// [`Animation`]: crate::animation::Animation
+    Frames(Vec<Grid<Rgb>>),
+    /// A closed polygon through the given corners.
+    Polygon(Vec<Point>),
+}
+
+impl Drawing {
+    /// The drawing as grids, rasterizing a polygon with [`rasterize`].
+    pub fn into_frames(self) -> Vec<Grid<Rgb>> {
+        match self {
+            Drawing::Grid(grid) => vec![grid],
+            Drawing::Frames(frames) => frames,
+            Drawing::Polygon(points) => vec![rasterize(&points)],
+        }
+    }
+}
+
+/// Colours a grid the same way an animation frame would be, on a dark background.
+pub fn paint<T: Paint>(grid: &Grid<T>) -> Grid<Rgb> {
+    grid.map(|cell| cell.color().map_or(Rgb::BACKGROUND, Rgb::from))
+}
+
+/// The smallest and largest row and column of any of the points.
+pub fn bounds(points: &[Point]) -> (Point, Point) {
+    let min = |f: fn(&Point) -> isize| points.iter().map(f).min().unwrap_or_default();
+    let max = |f: fn(&Point) -> isize| points.iter().map(f).max().unwrap_or_default();
+    (
+        Point::new(min(|p| p.row), min(|p| p.col)),
+        Point::new(max(|p| p.row), max(|p| p.col)),
+    )
+}
+
+/// Fills the polygon into a grid whose longer side is at most [`POLYGON_TILES`] tiles, and
+/// marks the tiles its edges pass through.
+pub fn rasterize(points: &[Point]) -> Grid<Rgb> {
+    let (min, max) = bounds(points);
+    let extent = (max.row - min.row).max(max.col - min.col).max(1) as f64;
+    let factor = ((POLYGON_TILES - 1) as f64 / extent).min(1.0);
+    let to_tile = |v: isize, min: isize| ((v - min) as f64 * factor) as usize;
+    let mut grid = Grid::filled(
+        to_tile(max.col, min.col) + 1,
+        to_tile(max.row, min.row) + 1,
+        Rgb::BACKGROUND,
+    );
+
+    let edges: Vec<(Point, Point)> = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+        .collect();
+    for r in 0..grid.height() {
+        let row = min.row as f64 + (r as f64 + 0.5) / factor;
+        let mut crossings: Vec<f64> = edges
+            .iter()
+            .filter(|(a, b)| (a.row as f64 <= row) != (b.row as f64 <= row))
+            .map(|(a, b)| {
+                let t = (row - a.row as f64) / (b.row - a.row) as f64;
+                a.col as f64 + t * (b.col - a.col) as f64
+            })
+            .collect();
+        crossings.sort_by(f64::total_cmp);
+        for pair in crossings.chunks_exact(2) {
+            for c in 0..grid.width() {
+                let col = min.col as f64 + (c as f64 + 0.5) / factor;
+                if pair[0] <= col && col < pair[1] {
+                    grid[(r, c)] = POLYGON_FILL;
+                }
+            }
+        }
+    }
+    for (a, b) in edges {
+        let steps = a.manhattan_distance(b).max(1);
+        for step in 0..=steps {
+            let t = step as f64 / steps as f64;
+            let row = a.row as f64 + t * (b.row - a.row) as f64;
+            let col = a.col as f64 + t * (b.col - a.col) as f64;
+            let r = ((row - min.row as f64) * factor) as usize;
+            let c = ((col - min.col as f64) * factor) as usize;
+            if let Some(tile) = grid.get_mut((r, c)) {
+                *tile = POLYGON_EDGE;
+            }
+        }
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mix_and_heat() {
+        assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 0.5), Rgb(128, 128, 128));
+        assert_eq!(Rgb::heat(0.0), Rgb(24, 24, 32));
+        assert_eq!(Rgb::heat(1.0), Rgb(255, 230, 80));
+        assert_eq!(Rgb(255, 230, 80).hex(), "#ffe650");
+    }
+
+    #[test]
+    fn rasterize_square() {
+        let square = [(0, 0), (0, 4), (4, 4), (4, 0)].map(|(r, c)| Point::new(r, c));
+        let grid = rasterize(&square);
+        assert_eq!((grid.width(), grid.height()), (5, 5));
+        assert_eq!(grid[(0, 2)], POLYGON_EDGE);
+        assert_eq!(grid[(2, 2)], POLYGON_FILL);
+        assert_eq!(grid[(4, 4)], POLYGON_EDGE);
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    env,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    Position,
+};
+
+/// A small seeded pseudo random number generator (SplitMix64), so the same seed generates the
+/// same input on every platform and with every build.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "cannot pick a number below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot pick a number from an empty range");
+        let len = end.abs_diff(start).wrapping_add(1);
+        match len {
+            // the range covers every i64
+            0 => self.next_u64() as i64,
+            len => start.wrapping_add((self.next_u64() % len) as i64),
+        }
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.below(idx + 1));
+        }
+    }
+}
+
+/// How large and how full a generated input should be. What exactly `size` counts and what
+/// `density` fills is up to each day's generator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    /// At least `1`.
+    pub size: usize,
+    /// Between `0` and `1`.
+    pub density: f64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            size: 20,
+            density: 0.5,
+        }
+    }
+}
+
+/// Checks `property` with generators seeded `0..cases`, naming the seed it fails for. The
+/// `AOC_CASES` environment variable overrides the number of cases.
+pub fn check_property(cases: u64, mut property: impl FnMut(&mut Rng)) {
+    let cases = env::var("AOC_CASES")
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(cases);
+    for seed in 0..cases {
+        let checked = panic::catch_unwind(AssertUnwindSafe(|| property(&mut Rng::new(seed))));
+        if let Err(payload) = checked {
+            eprintln!("property failed for seed {seed}");
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+/// A closed path of horizontal and vertical steps in a `size` by `size` grid, which never
+/// crosses or runs next to itself and winds around a random tree so that it encloses tiles.
+///
+/// Every position is followed by a neighbour, the last one by the first. `coverage` is roughly
+/// the share of the grid the path spreads over.
+pub fn random_loop(rng: &mut Rng, size: usize, coverage: f64) -> Vec<Position> {
+    // the tree's nodes are 4 tiles apart, every node is surrounded by a ring of 3 by 3 tiles
+    // and tiles between unconnected nodes stay free
+    let nodes = (size.max(3) + 1) / 4;
+    let target = ((nodes * nodes) as f64 * coverage).ceil().max(1.0) as usize;
+    let mut in_tree = vec![vec![false; nodes]; nodes];
+    let start = (rng.below(nodes), rng.below(nodes));
+    in_tree[start.0][start.1] = true;
+    let mut body = vec![(start.0 * 4 + 1, start.1 * 4 + 1)];
+    let mut frontier: Vec<(Position, Direction)> = vec![];
+    let mut node = start;
+    for _ in 1..target {
+        for direction in Direction::ALL {
+            frontier.push((node, direction));
+        }
+        frontier.retain(|(from, direction)| {
+            let to = Point::from(*from) + direction.delta();
+            to.to_position()
+                .is_some_and(|(r, c)| r < nodes && c < nodes && !in_tree[r][c])
+        });
+        if frontier.is_empty() {
+            break;
+        }
+        let (from, direction) = frontier.swap_remove(rng.below(frontier.len()));
+        node = direction.step(from);
+        in_tree[node.0][node.1] = true;
+        let center = (from.0 * 4 + 1, from.1 * 4 + 1);
+        body.extend((1..=4).scan(center, |pos, _| {
+            *pos = direction.step(*pos);
+            Some(*pos)
+        }));
+    }
+
+    let width = nodes * 4 - 1;
+    let mut tiles = Grid::filled(width, width, false);
+    for &pos in &body {
+        let around: Vec<Position> = tiles.neighbours8(pos).collect();
+        for pos in around {
+            tiles[pos] = true;
+        }
+    }
+    for &pos in &body {
+        tiles[pos] = false;
+    }
+
+    // the top left tile of the ring is a corner, from which we follow the ring around
+    let first = body.iter().min().map(|&(r, c)| (r - 1, c - 1)).unwrap();
+    let mut path = vec![first];
+    let mut prev = first;
+    let mut current = (first.0, first.1 + 1);
+    while current != first {
+        path.push(current);
+        let next = tiles
+            .neighbours(current)
+            .find(|&pos| tiles[pos] && pos != prev)
+            .unwrap();
+        prev = current;
+        current = next;
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rng_is_reproducible() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let rolls: Vec<i64> = (0..100).map(|_| a.range(-3..=3)).collect();
+        assert_eq!(rolls, (0..100).map(|_| b.range(-3..=3)).collect::<Vec<_>>());
+        assert!(rolls.iter().all(|r| (-3..=3).contains(r)));
+        assert!((-3..=3).all(|n| rolls.contains(&n)));
+        assert_ne!(Rng::new(8).next_u64(), Rng::new(7).next_u64());
+    }
+
+    #[test]
+    fn loops_are_closed_and_simple() {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let path = random_loop(&mut rng, 23, seed as f64 / 20.0);
+            let mut seen = std::collections::HashSet::new();
+            for (idx, pos) in path.iter().enumerate() {
+                assert!(seen.insert(*pos), "{pos:?} visited twice");
+                assert!(pos.0 < 23 && pos.1 < 23);
+                let next = path[(idx + 1) % path.len()];
+                assert_eq!(pos.0.abs_diff(next.0) + pos.1.abs_diff(next.1), 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    number::{add, mul, sub},
+    Position,
+};
+
+/// A `(row, column)` point that may leave a map or have negative coordinates. Its arithmetic
+/// goes through [`crate::number`], so it overflows like the solvers' own.
+///
+/// Rows grow downwards, so [`Direction::North`] has a negative row delta.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        add(self.row.abs_diff(other.row), self.col.abs_diff(other.col))
+    }
+
+    /// The map position of this point, if it has no negative coordinates.
+    pub fn to_position(self) -> Option<Position> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+}
+
+impl From<Position> for Point {
+    fn from(pos: Position) -> Self {
+        Self::new(pos.0 as isize, pos.1 as isize)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(add(self.row, rhs.row), add(self.col, rhs.col))
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(sub(self.row, rhs.row), sub(self.col, rhs.col))
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        Self::new(mul(self.row, rhs), mul(self.col, rhs))
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(sub(0, self.row), sub(0, self.col))
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+use Direction::*;
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            North => write!(f, "^"),
+            East => write!(f, ">"),
+            South => write!(f, "v"),
+            West => write!(f, "<"),
+        }
+    }
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting at north.
+    pub const ALL: [Self; 4] = [North, East, South, West];
+
+    /// Parses `U`, `R`, `D` or `L`.
+    pub fn from_udlr(c: char) -> Option<Self> {
+        match c {
+            'U' => Some(North),
+            'R' => Some(East),
+            'D' => Some(South),
+            'L' => Some(West),
+            _ => None,
+        }
+    }
+
+    /// Parses `N`, `E`, `S` or `W`.
+    pub fn from_nesw(c: char) -> Option<Self> {
+        match c {
+            'N' => Some(North),
+            'E' => Some(East),
+            'S' => Some(South),
+            'W' => Some(West),
+            _ => None,
+        }
+    }
+
+    /// The direction of a single step from `old_pos` to the neighbouring `new_pos`.
+    pub fn from_positions(old_pos: Position, new_pos: Position) -> Self {
+        if old_pos.1 < new_pos.1 {
+            East
+        } else if old_pos.1 > new_pos.1 {
+            West
+        } else if old_pos.0 < new_pos.0 {
+            South
+        } else {
+            North
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            North => West,
+            East => North,
+            South => East,
+            West => South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+        }
+    }
+
+    pub fn turn_back(self) -> Self {
+        match self {
+            North => South,
+            East => West,
+            South => North,
+            West => East,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, North | South)
+    }
+
+    /// The offset of one step in this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            North => Point::new(-1, 0),
+            East => Point::new(0, 1),
+            South => Point::new(1, 0),
+            West => Point::new(0, -1),
+        }
+    }
+
+    /// Takes one step from `old_pos`, panicking when stepping off the top or left edge.
+    pub fn step(self, old_pos: Position) -> Position {
+        (Point::from(old_pos) + self.delta())
+            .to_position()
+            .expect("stepped off the top or left edge")
+    }
+}
+
+/// The four directions halfway between two cardinal [`Direction`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Diagonal {
+    NorthEast,
+    SouthEast,
+    SouthWest,
+    NorthWest,
+}
+use Diagonal::*;
+
+impl Diagonal {
+    /// All diagonals in clockwise order, starting at north-east.
+    pub const ALL: [Self; 4] = [NorthEast, SouthEast, SouthWest, NorthWest];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            NorthEast => NorthWest,
+            SouthEast => NorthEast,
+            SouthWest => SouthEast,
+            NorthWest => SouthWest,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            NorthEast => SouthEast,
+            SouthEast => SouthWest,
+            SouthWest => NorthWest,
+            NorthWest => NorthEast,
+        }
+    }
+
+    pub fn turn_back(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The two cardinal directions this diagonal lies between, vertical one first.
+    pub fn components(self) -> (Direction, Direction) {
+        match self {
+            NorthEast => (North, East),
+            SouthEast => (South, East),
+            SouthWest => (South, West),
+            NorthWest => (North, West),
+        }
+    }
+
+    /// The offset of one step in this direction.
+    pub fn delta(self) -> Point {
+        let (vertical, horizontal) = self.components();
+        vertical.delta() + horizontal.delta()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning_around() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.turn_back());
+            assert_eq!(direction.delta(), -direction.turn_back().delta());
+        }
+        for diagonal in Diagonal::ALL {
+            assert_eq!(diagonal.turn_left().turn_right(), diagonal);
+            assert_eq!(diagonal.delta(), -diagonal.turn_back().delta());
+        }
+    }
+
+    #[test]
+    fn char_styles_agree() {
+        for (udlr, nesw) in [('U', 'N'), ('R', 'E'), ('D', 'S'), ('L', 'W')] {
+            assert_eq!(Direction::from_udlr(udlr), Direction::from_nesw(nesw));
+        }
+        assert_eq!(Direction::from_udlr('N'), None);
+    }
+
+    #[test]
+    fn point_arithmetic() {
+        let point = Point::new(2, -3) + East.delta() * 4 - NorthWest.delta();
+        assert_eq!(point, Point::new(3, 2));
+        assert_eq!(point.manhattan_distance(Point::ORIGIN), 5);
+        assert_eq!(point.to_position(), Some((3, 2)));
+        assert_eq!((-point).to_position(), None);
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn point_arithmetic_overflows() {
+        use crate::number::{catch_overflow, Overflow};
+
+        let far = Point::new(isize::MAX / 2 + 1, 0);
+        assert_eq!(catch_overflow(|| far * 2), Err(Overflow { op: "multiply" }));
+        assert_eq!(
+            catch_overflow(|| {
+                let mut point = far;
+                point += far;
+            }),
+            Err(Overflow { op: "add" })
+        );
+        assert_eq!(
+            catch_overflow(|| -Point::new(isize::MIN, 0)),
+            Err(Overflow { op: "subtract" })
+        );
+    }
+}
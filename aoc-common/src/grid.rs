@@ -0,0 +1,323 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{
+    geometry::{Diagonal, Direction, Point},
+    parse::{lines, Line, ParseError},
+    Position,
+};
+
+/// Tiles that can be read from a single character of a map.
+pub trait FromChar: Sized {
+    /// Describes the valid characters for error messages.
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+}
+
+/// A rectangular map stored row by row in a single `Vec`, indexed by `(row, column)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order, panicking if they don't fill it exactly.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "grid cells don't match its size"
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Self::new(width, height, cells)
+    }
+
+    /// Parses a character map line by line, converting every character with `from_char`.
+    ///
+    /// Characters `from_char` rejects and rows of differing width are reported as errors, with
+    /// `expected` describing the valid characters.
+    pub fn parse(
+        input_str: &str,
+        expected: &str,
+        from_char: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let lines: Vec<Line> = lines(input_str.trim_end()).collect();
+        if lines.is_empty() {
+            return Err(ParseError::end_of_input(input_str, "a map"));
+        }
+        Self::parse_lines(&lines, expected, from_char)
+    }
+
+    /// Like [`Grid::parse`], for a map that is only part of the input, e.g. one of its blocks.
+    pub fn parse_lines(
+        lines: &[Line],
+        expected: &str,
+        mut from_char: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        for line in lines {
+            let row_start = cells.len();
+            for (idx, c) in line.text.char_indices() {
+                let tile = from_char(c)
+                    .ok_or_else(|| line.error(&line.text[idx..idx + c.len_utf8()], expected))?;
+                cells.push(tile);
+            }
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if row_width != width => {
+                    let rest = line
+                        .text
+                        .char_indices()
+                        .nth(width)
+                        .map_or("", |(idx, _)| &line.text[idx..]);
+                    return Err(line.error(rest, format!("a row of {width} tiles")));
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(Self::new(width.unwrap_or(0), lines.len(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        (pos.0 < self.height && pos.1 < self.width).then(|| &self.cells[self.index_of(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if pos.0 < self.height && pos.1 < self.width {
+            let idx = self.index_of(pos);
+            Some(&mut self.cells[idx])
+        } else {
+            None
+        }
+    }
+
+    /// The position `point` refers to, if it lies inside the grid.
+    pub fn checked_position(&self, point: Point) -> Option<Position> {
+        point
+            .to_position()
+            .filter(|pos| pos.0 < self.height && pos.1 < self.width)
+    }
+
+    pub fn get_signed(&self, point: Point) -> Option<&T> {
+        self.checked_position(point).map(|pos| &self[pos])
+    }
+
+    pub fn get_signed_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.checked_position(point).map(|pos| &mut self[pos])
+    }
+
+    /// Indexes the grid as if it was repeated infinitely in every direction.
+    pub fn get_wrapping(&self, point: Point) -> &T {
+        &self[(
+            point.row.rem_euclid(self.height as isize) as usize,
+            point.col.rem_euclid(self.width as isize) as usize,
+        )]
+    }
+
+    /// Takes one step from `pos`, unless that leaves the grid.
+    pub fn step(&self, pos: Position, direction: Direction) -> Option<Position> {
+        self.checked_position(Point::from(pos) + direction.delta())
+    }
+
+    /// The up to four orthogonally adjacent positions inside the grid.
+    pub fn neighbours(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The up to eight orthogonally or diagonally adjacent positions inside the grid.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .map(Direction::delta)
+            .into_iter()
+            .chain(Diagonal::ALL.map(Diagonal::delta))
+            .filter_map(move |delta| self.checked_position(Point::from(pos) + delta))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Iterates over all cells in row-major order together with their positions.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0, which an empty grid would have
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    /// The position of the first cell in row-major order matching `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    fn index_of(&self, pos: Position) -> usize {
+        pos.0 * self.width + pos.1
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90°, so its left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(row, col)| {
+            self[(self.height - 1 - col, row)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90°, so its right column becomes the top row.
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, self.width - 1 - row)].clone()
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {pos:?} is outside of a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} is outside of a {width}x{height} grid"))
+    }
+}
+
+impl<T: FromChar> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, T::EXPECTED, T::from_char)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx != 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<char> {
+        Grid::parse("123\n456\n", "a digit", |c| c.is_ascii_digit().then_some(c)).unwrap()
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let error = Grid::parse("123\n45\n", "a digit", Some).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a row of 3 tiles, found end of line"
+        );
+    }
+
+    #[test]
+    fn checked_and_wrapping_indexing() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((1, 2)), Some(&'6'));
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get_signed(Point::new(-1, 0)), None);
+        assert_eq!(grid.get_wrapping(Point::new(-1, 4)), &'5');
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = digits();
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn rotations_and_transposition() {
+        let grid = digits();
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
@@ -0,0 +1,16 @@
+pub mod animation;
+pub mod combinator;
+pub mod cycle;
+pub mod drawing;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod memo;
+pub mod number;
+pub mod parallel;
+pub mod parse;
+pub mod repl;
+pub mod solution;
+
+/// A `(row, column)` position inside a map.
+pub type Position = (usize, usize);
@@ -0,0 +1,173 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::AddAssign,
+};
+
+/// How often a [`Memo`] or [`Table`] looked up a value it had already computed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    /// Every value computed counts as a miss.
+    pub misses: u64,
+}
+
+impl Stats {
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+impl AddAssign for Stats {
+    fn add_assign(&mut self, other: Self) {
+        self.hits += other.hits;
+        self.misses += other.misses;
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+/// Top-down memoization of a recursive function `f`, computing values on demand and caching
+/// every value computed on the way.
+///
+/// `f` gets the key to compute the value of and a function to look up the values of the keys it
+/// depends on, the same way [`tabulate`] calls it. Keys should be cheap to hash, like offsets into
+/// the slices the function works on rather than the slices themselves.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: Stats,
+}
+
+impl<K: Hash + Eq + Copy, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+            stats: Stats::default(),
+        }
+    }
+
+    pub fn get(&mut self, key: K, f: &impl Fn(&mut dyn FnMut(K) -> V, K) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = f(&mut |key| self.get(key, f), key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+impl<K: Hash + Eq + Copy, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The values [`tabulate`] computed.
+#[derive(Debug, Clone)]
+pub struct Table<K, V> {
+    values: HashMap<K, V>,
+    stats: Stats,
+}
+
+impl<K: Hash + Eq, V> Table<K, V> {
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+/// Bottom-up tabulation of a recursive function `f`, computing the value of every key in `order`
+/// without recursing. `f` is called like for a [`Memo`], and may only look up keys that come
+/// before the key it computes in `order`.
+///
+/// # Panics
+///
+/// If `f` looks up a key whose value has not been computed yet.
+pub fn tabulate<K, V>(
+    order: impl IntoIterator<Item = K>,
+    f: impl Fn(&mut dyn FnMut(K) -> V, K) -> V,
+) -> Table<K, V>
+where
+    K: Hash + Eq + Copy + Debug,
+    V: Clone,
+{
+    let mut table = Table {
+        values: HashMap::<K, V>::new(),
+        stats: Stats::default(),
+    };
+    for key in order {
+        let mut lookup = |dependency| match table.values.get(&dependency) {
+            Some(value) => {
+                table.stats.hits += 1;
+                value.clone()
+            }
+            None => panic!("{key:?} depends on {dependency:?}, which comes after it"),
+        };
+        let value = f(&mut lookup, key);
+        table.stats.misses += 1;
+        table.values.insert(key, value);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(lookup: &mut dyn FnMut(u64) -> u64, n: u64) -> u64 {
+        match n {
+            0 | 1 => n,
+            n => lookup(n - 1) + lookup(n - 2),
+        }
+    }
+
+    #[test]
+    fn both_modes_agree() {
+        let mut memo = Memo::new();
+        assert_eq!(memo.get(90, &fibonacci), 2_880_067_194_370_816_120);
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 88,
+                misses: 91
+            }
+        );
+        let table = tabulate(0..=90, fibonacci);
+        assert_eq!(table.get(&90), Some(&2_880_067_194_370_816_120));
+        assert_eq!(table.stats().misses, 91);
+        assert_eq!(
+            table.stats().to_string(),
+            "178 hits, 91 misses (66.2% hit rate)"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "10 depends on 9, which comes after it")]
+    fn tabulating_out_of_order() {
+        tabulate((0..=10).rev(), fibonacci);
+    }
+}
@@ -0,0 +1,185 @@
+//! The arithmetic solvers compute their answers with.
+//!
+//! Built with the `checked` feature, every operation here checks for overflow, which fails the
+//! part with an [`Overflow`] error instead of wrapping around. Built with the `wide` feature,
+//! answers are computed in 128 bits, so that inputs scaled far beyond the puzzle's still give
+//! exact answers. Counts of things held in memory, like tiles of a grid, stay `usize` either way.
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul, Sub},
+    panic::{self, AssertUnwindSafe},
+};
+
+/// The unsigned type answers are computed in.
+#[cfg(not(feature = "wide"))]
+pub type Uint = u64;
+#[cfg(feature = "wide")]
+pub type Uint = u128;
+
+/// The signed type answers are computed in.
+#[cfg(not(feature = "wide"))]
+pub type Int = i64;
+#[cfg(feature = "wide")]
+pub type Int = i128;
+
+const CHECKED: bool = cfg!(feature = "checked");
+
+/// An operation whose result did not fit its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub op: &'static str,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "attempt to {} with overflow", self.op)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// The integer types the operations here work on.
+pub trait Integer:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Display
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    fn pow(self, exp: u32) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                self.checked_add(other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                self.checked_sub(other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                self.checked_mul(other)
+            }
+
+            fn checked_pow(self, exp: u32) -> Option<Self> {
+                self.checked_pow(exp)
+            }
+
+            fn pow(self, exp: u32) -> Self {
+                self.pow(exp)
+            }
+        }
+    )*};
+}
+
+integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Unwinds out of the part being solved, which [`catch_overflow`] turns into an error. Unlike a
+/// panic this does not print a message on the way.
+///
+/// Where panics abort instead, like on `wasm32`, nothing can catch the overflow, so it panics
+/// with the [`Overflow`] as payload for the panic hook to report.
+fn overflow(op: &'static str) -> ! {
+    if cfg!(panic = "abort") {
+        panic::panic_any(Overflow { op })
+    }
+    panic::resume_unwind(Box::new(Overflow { op }))
+}
+
+pub fn add<T: Integer>(a: T, b: T) -> T {
+    if CHECKED {
+        a.checked_add(b).unwrap_or_else(|| overflow("add"))
+    } else {
+        a + b
+    }
+}
+
+pub fn sub<T: Integer>(a: T, b: T) -> T {
+    if CHECKED {
+        a.checked_sub(b).unwrap_or_else(|| overflow("subtract"))
+    } else {
+        a - b
+    }
+}
+
+pub fn mul<T: Integer>(a: T, b: T) -> T {
+    if CHECKED {
+        a.checked_mul(b).unwrap_or_else(|| overflow("multiply"))
+    } else {
+        a * b
+    }
+}
+
+pub fn pow<T: Integer>(base: T, exp: u32) -> T {
+    if CHECKED {
+        base.checked_pow(exp)
+            .unwrap_or_else(|| overflow("multiply"))
+    } else {
+        base.pow(exp)
+    }
+}
+
+pub fn sum<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ZERO, add)
+}
+
+pub fn product<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ONE, mul)
+}
+
+/// Calls `f`, returning the overflow it ran into instead.
+pub fn catch_overflow<T>(f: impl FnOnce() -> T) -> Result<T, Overflow> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => Ok(value),
+        Err(payload) => match payload.downcast::<Overflow>() {
+            Ok(overflow) => Err(*overflow),
+            Err(payload) => panic::resume_unwind(payload),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations() {
+        assert_eq!(sum([1u32, 2, 3]), 6);
+        assert_eq!(product([2u64, 3, 4]), 24);
+        assert_eq!(pow(2u64, 10), 1024);
+        assert_eq!(sub(mul(3i64, 4), 20), -8);
+        assert_eq!(catch_overflow(|| add(1, 2)), Ok(3));
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn overflow_is_an_error() {
+        assert_eq!(
+            catch_overflow(|| mul(u32::MAX, 2)),
+            Err(Overflow { op: "multiply" })
+        );
+        assert_eq!(
+            catch_overflow(|| sum([u8::MAX, 1]))
+                .unwrap_err()
+                .to_string(),
+            "attempt to add with overflow"
+        );
+    }
+
+    #[cfg(feature = "wide")]
+    #[test]
+    fn answers_are_wide() {
+        assert_eq!(Uint::MAX, u128::MAX);
+        assert_eq!(Int::MIN, i128::MIN);
+    }
+}
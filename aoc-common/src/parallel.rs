@@ -0,0 +1,114 @@
+//! Work split over threads, for the runner and the heaviest solvers.
+//!
+//! Built with the `parallel` feature this runs on rayon's global thread pool, otherwise, and on
+//! `wasm32` which has no threads, everything runs one after another. Results always come back in
+//! order, so answers do not depend on how the work was scheduled.
+//!
+//! The solvers' own parallel paths, [`solver_map`] and [`solver_join`], are opt-in with the
+//! `parallel-solvers` feature, so that by default a day is solved on a single thread while the
+//! runner solves the days side by side.
+
+const SOLVERS: bool = cfg!(feature = "parallel-solvers");
+
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+mod imp {
+    use rayon::prelude::*;
+
+    pub fn map<T: Send, R: Send>(items: Vec<T>, f: impl Fn(T) -> R + Sync + Send) -> Vec<R> {
+        items.into_par_iter().map(f).collect()
+    }
+
+    pub fn join<A: Send, B: Send>(
+        a: impl FnOnce() -> A + Send,
+        b: impl FnOnce() -> B + Send,
+    ) -> (A, B) {
+        rayon::join(a, b)
+    }
+
+    pub fn set_threads(threads: usize) -> Result<(), String> {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(|e| format!("could not start {threads} threads: {e}"))
+    }
+}
+
+#[cfg(not(all(feature = "parallel", not(target_arch = "wasm32"))))]
+mod imp {
+    pub fn map<T: Send, R: Send>(items: Vec<T>, f: impl Fn(T) -> R + Sync + Send) -> Vec<R> {
+        items.into_iter().map(f).collect()
+    }
+
+    pub fn join<A: Send, B: Send>(
+        a: impl FnOnce() -> A + Send,
+        b: impl FnOnce() -> B + Send,
+    ) -> (A, B) {
+        (a(), b())
+    }
+
+    pub fn set_threads(threads: usize) -> Result<(), String> {
+        match threads {
+            1 => Ok(()),
+            _ => Err("threads are only available with the `parallel` feature".to_string()),
+        }
+    }
+}
+
+/// Calls `f` on every item, in parallel, returning the results in the order of `items`.
+pub fn map<T: Send, R: Send>(items: Vec<T>, f: impl Fn(T) -> R + Sync + Send) -> Vec<R> {
+    imp::map(items, f)
+}
+
+/// Calls `a` and `b`, in parallel.
+pub fn join<A: Send, B: Send>(
+    a: impl FnOnce() -> A + Send,
+    b: impl FnOnce() -> B + Send,
+) -> (A, B) {
+    imp::join(a, b)
+}
+
+/// Like [`map`] for the work inside a solver, only in parallel with `parallel-solvers`.
+pub fn solver_map<T: Send, R: Send>(items: Vec<T>, f: impl Fn(T) -> R + Sync + Send) -> Vec<R> {
+    if SOLVERS {
+        imp::map(items, f)
+    } else {
+        items.into_iter().map(f).collect()
+    }
+}
+
+/// Like [`join`] for the work inside a solver, only in parallel with `parallel-solvers`.
+pub fn solver_join<A: Send, B: Send>(
+    a: impl FnOnce() -> A + Send,
+    b: impl FnOnce() -> B + Send,
+) -> (A, B) {
+    if SOLVERS {
+        imp::join(a, b)
+    } else {
+        (a(), b())
+    }
+}
+
+/// Sets how many threads do the work, which has to happen before anything ran in parallel.
+pub fn set_threads(threads: usize) -> Result<(), String> {
+    imp::set_threads(threads)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_keep_their_order() {
+        let items: Vec<u64> = (0..1000).collect();
+        assert_eq!(
+            map(items.clone(), |n| n * n),
+            items.iter().map(|n| n * n).collect::<Vec<_>>()
+        );
+        assert_eq!(join(|| 1, || "two"), (1, "two"));
+        assert_eq!(
+            solver_map(items.clone(), |n| n + 1),
+            items.iter().map(|n| n + 1).collect::<Vec<_>>()
+        );
+        assert_eq!(solver_join(|| 1, || "two"), (1, "two"));
+    }
+}
@@ -0,0 +1,147 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// What a parser ran into instead of the token it expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Found {
+    Token(String),
+    EndOfLine,
+    EndOfInput,
+}
+
+impl Display for Found {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Found::Token(token) if token.is_empty() => write!(f, "nothing"),
+            Found::Token(token) => write!(f, "`{}`", token.escape_debug()),
+            Found::EndOfLine => write!(f, "end of line"),
+            Found::EndOfInput => write!(f, "end of input"),
+        }
+    }
+}
+
+/// A puzzle input that could not be parsed, located by its 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: Found,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+impl ParseError {
+    /// An error for input that ends before `expected` was read.
+    pub fn end_of_input(input_str: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line: input_str.lines().count() + 1,
+            column: 1,
+            expected: expected.into(),
+            found: Found::EndOfInput,
+        }
+    }
+}
+
+/// A single line of the input together with its 1-based line number.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// The 1-based column at which `token`, a slice of this line, starts.
+    pub fn column(&self, token: &str) -> usize {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| offset + token.len() <= self.text.len())
+            .unwrap_or(self.text.len());
+        self.text[..offset].chars().count() + 1
+    }
+
+    /// An error pointing at `token`, which has to be a slice of this line.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let column = self.column(token);
+        let found = if token.is_empty() && column == self.text.chars().count() + 1 {
+            Found::EndOfLine
+        } else {
+            Found::Token(token.to_string())
+        };
+        ParseError {
+            line: self.number,
+            column,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    pub fn end_error(&self, expected: impl Into<String>) -> ParseError {
+        self.error(&self.text[self.text.len()..], expected)
+    }
+
+    /// Unwraps a token taken from an iterator over this line, reporting a missing one as an
+    /// error at the end of the line.
+    pub fn token(
+        &self,
+        token: Option<&'a str>,
+        expected: impl Into<String>,
+    ) -> Result<&'a str, ParseError> {
+        token.ok_or_else(|| self.end_error(expected))
+    }
+
+    pub fn parse<T: FromStr>(
+        &self,
+        token: &'a str,
+        expected: impl Into<String>,
+    ) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Splits `token` at the first `delimiter`, reporting a missing delimiter after `token`.
+    pub fn split_once(
+        &self,
+        token: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        token
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(&token[token.len()..], format!("`{delimiter}`")))
+    }
+}
+
+pub fn lines(input_str: &str) -> impl Iterator<Item = Line<'_>> {
+    input_str.lines().enumerate().map(|(idx, text)| Line {
+        number: idx + 1,
+        text,
+    })
+}
+
+/// Groups the lines of the input into blocks separated by blank lines.
+pub fn blocks(input_str: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![];
+    let mut current_block = vec![];
+    for line in lines(input_str) {
+        if line.text.trim().is_empty() {
+            if !current_block.is_empty() {
+                blocks.push(current_block);
+                current_block = vec![];
+            }
+        } else {
+            current_block.push(line);
+        }
+    }
+    if !current_block.is_empty() {
+        blocks.push(current_block);
+    }
+    blocks
+}
@@ -0,0 +1,148 @@
+//! Commands to explore a day's parsed input with, as `aoc repl` offers them.
+
+use crate::number::catch_overflow;
+
+/// A command run against the parsed input, given the rest of the line after its name.
+pub struct Command<I> {
+    pub name: &'static str,
+    /// The arguments, like `<L|R> from <node>`.
+    pub usage: &'static str,
+    pub help: &'static str,
+    pub run: fn(&I, &str) -> Result<String, String>,
+    /// The words the arguments are made of, offered for tab completion.
+    pub words: fn(&I) -> Vec<String>,
+}
+
+/// A command's name, arguments and what it does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Help {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub help: &'static str,
+}
+
+trait Explore {
+    fn find(&self, name: &str) -> Option<usize>;
+    fn run(&self, command: usize, args: &str) -> Result<String, String>;
+    fn words(&self, command: usize) -> Vec<String>;
+}
+
+struct Bound<I> {
+    input: I,
+    commands: Vec<Command<I>>,
+}
+
+impl<I> Explore for Bound<I> {
+    fn find(&self, name: &str) -> Option<usize> {
+        self.commands.iter().position(|c| c.name == name)
+    }
+
+    fn run(&self, command: usize, args: &str) -> Result<String, String> {
+        (self.commands[command].run)(&self.input, args)
+    }
+
+    fn words(&self, command: usize) -> Vec<String> {
+        (self.commands[command].words)(&self.input)
+    }
+}
+
+/// A parsed input together with the day's commands, whatever the type of the input.
+pub struct Session {
+    help: Vec<Help>,
+    bound: Box<dyn Explore>,
+}
+
+impl Session {
+    pub fn new<I: 'static>(input: I, commands: Vec<Command<I>>) -> Self {
+        let help = commands
+            .iter()
+            .map(|c| Help {
+                name: c.name,
+                usage: c.usage,
+                help: c.help,
+            })
+            .collect();
+        Self {
+            help,
+            bound: Box::new(Bound { input, commands }),
+        }
+    }
+
+    pub fn commands(&self) -> &[Help] {
+        &self.help
+    }
+
+    /// Runs a line like `next L from AAA`, failing with the overflow a command ran into.
+    pub fn run(&self, line: &str) -> Result<String, String> {
+        let line = line.trim();
+        let (name, args) = line.split_once(' ').unwrap_or((line, ""));
+        let command = self
+            .bound
+            .find(name)
+            .ok_or_else(|| format!("unknown command `{name}`"))?;
+        catch_overflow(|| self.bound.run(command, args.trim())).map_err(|e| e.to_string())?
+    }
+
+    /// The words the arguments of the command `name` are made of.
+    pub fn words(&self, name: &str) -> Vec<String> {
+        self.bound
+            .find(name)
+            .map_or(vec![], |command| self.bound.words(command))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_commands_by_name() {
+        let session = Session::new(
+            vec![3, 1, 2],
+            vec![Command {
+                name: "nth",
+                usage: "<index>",
+                help: "the number at an index",
+                run: |numbers, args| {
+                    let idx: usize = args.parse().map_err(|_| "expected an index")?;
+                    numbers
+                        .get(idx)
+                        .map(|n| n.to_string())
+                        .ok_or_else(|| format!("there are only {} numbers", numbers.len()))
+                },
+                words: |numbers| (0..numbers.len()).map(|idx| idx.to_string()).collect(),
+            }],
+        );
+        assert_eq!(session.commands()[0].usage, "<index>");
+        assert_eq!(session.run("nth  1 "), Ok("1".to_string()));
+        assert_eq!(
+            session.run("nth 5"),
+            Err("there are only 3 numbers".to_string())
+        );
+        assert_eq!(
+            session.run("first"),
+            Err("unknown command `first`".to_string())
+        );
+        assert_eq!(session.words("nth"), ["0", "1", "2"]);
+        assert!(session.words("first").is_empty());
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn overflows_are_errors() {
+        let session = Session::new(
+            u8::MAX,
+            vec![Command {
+                name: "double",
+                usage: "",
+                help: "twice the number",
+                run: |n, _| Ok(crate::number::mul(*n, 2).to_string()),
+                words: |_| vec![],
+            }],
+        );
+        assert_eq!(
+            session.run("double"),
+            Err("attempt to multiply with overflow".to_string())
+        );
+    }
+}
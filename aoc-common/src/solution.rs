@@ -0,0 +1,309 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use crate::{
+    animation::Animation,
+    drawing::Drawing,
+    generate::{Options, Rng},
+    number::Overflow,
+    parallel,
+    parse::ParseError,
+    repl::{Command, Session},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// The answer to a single part, `None` marking a part that has not been solved.
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub part: Part,
+    pub answer: Option<String>,
+    pub time: Duration,
+}
+
+/// The answers to the requested parts, together with how long parsing the input took.
+#[derive(Debug, Clone)]
+pub struct Answers {
+    pub parse_time: Duration,
+    pub parts: Vec<Answer>,
+}
+
+/// Why a day could not be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// Only reported with the `checked` feature, for the part being solved if any.
+    Overflow(Option<Part>, Overflow),
+    /// The input parsed, but has no answer, for the part being solved if any.
+    Unsolvable(Option<Part>, Unsolvable),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "could not parse the input: {e}"),
+            SolveError::Overflow(Some(part), overflow) => write!(f, "part {part}: {overflow}"),
+            SolveError::Overflow(None, overflow) => write!(f, "{overflow}"),
+            SolveError::Unsolvable(Some(part), reason) => write!(f, "part {part}: {reason}"),
+            SolveError::Unsolvable(None, reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+/// Why an input has no answer to a part, like a path that never arrives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unsolvable(pub String);
+
+impl Display for Unsolvable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Gives up on the part being solved, which is then reported as [`SolveError::Unsolvable`].
+/// Like an overflow it unwinds without printing a message, and panics with the [`Unsolvable`]
+/// as payload where panics abort.
+pub fn unsolvable(reason: impl Into<String>) -> ! {
+    let reason = Unsolvable(reason.into());
+    if cfg!(panic = "abort") {
+        panic::panic_any(reason)
+    }
+    panic::resume_unwind(Box::new(reason))
+}
+
+/// A single day's puzzle: how to parse the input and how to solve both parts of it.
+pub trait Solution {
+    const DAY: u8;
+    /// Shared by the parts solved in parallel, and kept by `aoc repl` sessions.
+    type Input: Sync + 'static;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> impl Display;
+    /// Days without a solved second part keep the default.
+    fn part2(_input: &Self::Input) -> Option<impl Display> {
+        None::<String>
+    }
+    /// Shows the solution at work frame by frame, days without an animation keep the default.
+    fn animate(_input: &Self::Input, _animation: &mut Animation) {}
+    /// Draws a picture of the solution, days without one keep the default.
+    fn draw(_input: &Self::Input) -> Option<Drawing> {
+        None
+    }
+    /// Generates a random valid input, for days with a generator.
+    fn generate(_rng: &mut Rng, _options: &Options) -> Option<String> {
+        None
+    }
+    /// Commands to explore the parsed input with in `aoc repl`, days without any keep the default.
+    fn commands() -> Vec<Command<Self::Input>> {
+        vec![]
+    }
+}
+
+/// How long each benchmark iteration took to parse the input and to solve each part.
+#[derive(Debug, Default, Clone)]
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    /// Empty for days without a solved second part.
+    pub part2: Vec<Duration>,
+}
+
+/// Type-erased handle on a [`Solution`], so the runner can look days up by number.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    run: fn(&str, &[Part], bool) -> Result<Answers, SolveError>,
+    bench: fn(&str, usize) -> Result<Timings, SolveError>,
+    animate: fn(&str, &mut Animation) -> Result<(), SolveError>,
+    draw: fn(&str) -> Result<Option<Drawing>, SolveError>,
+    generate: fn(&mut Rng, &Options) -> Option<String>,
+    repl: fn(&str) -> Result<Session, SolveError>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            run: run::<S>,
+            bench: bench::<S>,
+            animate: animate::<S>,
+            draw: draw::<S>,
+            generate: S::generate,
+            repl: repl::<S>,
+        }
+    }
+
+    /// Parses `input_str` once and solves the requested parts one after another, so that each
+    /// part's time is its own.
+    pub fn run(&self, input_str: &str, parts: &[Part]) -> Result<Answers, SolveError> {
+        (self.run)(input_str, parts, false)
+    }
+
+    /// Like [`Day::run`], but solves the parts side by side. Their times then include waiting
+    /// for the other work on the thread pool.
+    pub fn run_concurrently(&self, input_str: &str, parts: &[Part]) -> Result<Answers, SolveError> {
+        (self.run)(input_str, parts, true)
+    }
+
+    /// Times parsing and solving both parts over `iterations` independent runs.
+    pub fn bench(&self, input_str: &str, iterations: usize) -> Result<Timings, SolveError> {
+        (self.bench)(input_str, iterations)
+    }
+
+    pub fn animate(&self, input_str: &str, animation: &mut Animation) -> Result<(), SolveError> {
+        (self.animate)(input_str, animation)
+    }
+
+    pub fn draw(&self, input_str: &str) -> Result<Option<Drawing>, SolveError> {
+        (self.draw)(input_str)
+    }
+
+    pub fn generate(&self, rng: &mut Rng, options: &Options) -> Option<String> {
+        (self.generate)(rng, options)
+    }
+
+    /// Parses `input_str` once for a session running the day's commands on it.
+    pub fn repl(&self, input_str: &str) -> Result<Session, SolveError> {
+        (self.repl)(input_str)
+    }
+}
+
+/// Calls `f` and measures how long it took.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = std::time::Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
+/// `wasm32-unknown-unknown` has no clock, where everything is reported to take no time.
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    (f(), Duration::ZERO)
+}
+
+/// Calls `f`, returning the overflow it ran into or the reason it gave up instead, for `part`
+/// if it is solving one.
+fn catch<T>(part: Option<Part>, f: impl FnOnce() -> T) -> Result<T, SolveError> {
+    let payload = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => return Ok(value),
+        Err(payload) => payload,
+    };
+    let payload = match payload.downcast::<Overflow>() {
+        Ok(overflow) => return Err(SolveError::Overflow(part, *overflow)),
+        Err(payload) => payload,
+    };
+    match payload.downcast::<Unsolvable>() {
+        Ok(reason) => Err(SolveError::Unsolvable(part, *reason)),
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Calls `f`, which runs the day's code outside of solving a part, like parsing the input.
+fn checked<T>(f: impl FnOnce() -> Result<T, SolveError>) -> Result<T, SolveError> {
+    catch(None, f)?
+}
+
+fn parse<S: Solution>(input_str: &str) -> Result<S::Input, SolveError> {
+    checked(|| Ok(S::parse(input_str)?))
+}
+
+/// Solves a part, `None` if it is not solved.
+fn solve<S: Solution>(input: &S::Input, part: Part) -> Result<Option<String>, SolveError> {
+    catch(Some(part), || match part {
+        Part::One => Some(S::part1(input).to_string()),
+        Part::Two => S::part2(input).map(|answer| answer.to_string()),
+    })
+}
+
+fn run<S: Solution>(
+    input_str: &str,
+    parts: &[Part],
+    concurrently: bool,
+) -> Result<Answers, SolveError> {
+    let (input, parse_time) = timed(|| parse::<S>(input_str));
+    let input = input?;
+    let answer = |part| {
+        let (answer, time) = timed(|| solve::<S>(&input, part));
+        Ok(Answer {
+            part,
+            answer: answer?,
+            time,
+        })
+    };
+    let parts = if concurrently {
+        parallel::map(parts.to_vec(), answer)
+    } else {
+        parts.iter().copied().map(answer).collect()
+    };
+    let parts = parts.into_iter().collect::<Result<_, SolveError>>()?;
+    Ok(Answers { parse_time, parts })
+}
+
+fn bench<S: Solution>(input_str: &str, iterations: usize) -> Result<Timings, SolveError> {
+    let mut timings = Timings::default();
+    for _ in 0..iterations {
+        let (input, time) = timed(|| parse::<S>(black_box(input_str)));
+        let input = input?;
+        timings.parse.push(time);
+
+        let (answer, time) = timed(|| black_box(solve::<S>(&input, Part::One)));
+        answer?;
+        timings.part1.push(time);
+
+        let (answer, time) = timed(|| black_box(solve::<S>(&input, Part::Two)));
+        if answer?.is_some() {
+            timings.part2.push(time);
+        }
+    }
+    Ok(timings)
+}
+
+fn animate<S: Solution>(input_str: &str, animation: &mut Animation) -> Result<(), SolveError> {
+    let input = parse::<S>(input_str)?;
+    checked(|| {
+        S::animate(&input, animation);
+        Ok(())
+    })
+}
+
+fn draw<S: Solution>(input_str: &str) -> Result<Option<Drawing>, SolveError> {
+    let input = parse::<S>(input_str)?;
+    checked(|| Ok(S::draw(&input)))
+}
+
+/// The session's commands catch their own overflows.
+fn repl<S: Solution>(input_str: &str) -> Result<Session, SolveError> {
+    Ok(Session::new(parse::<S>(input_str)?, S::commands()))
+}
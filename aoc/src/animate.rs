@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+
+use aoc_common::animation::{Animation, Options};
+use clap::Args;
+
+use crate::{
+    days,
+    input::{self, Source},
+};
+
+#[derive(Args)]
+pub struct AnimateArgs {
+    /// Day number
+    day: u8,
+    /// Read the input from this file, or `-` for stdin, instead of `dayN/input.txt`
+    #[arg(short, long)]
+    input: Option<Source>,
+    /// Frames per second, `0` for as fast as possible
+    #[arg(long, default_value_t = 20.0)]
+    fps: f64,
+    /// Draw the frames without ANSI colours
+    #[arg(long)]
+    no_color: bool,
+    /// Start paused, pressing Enter steps a single frame
+    #[arg(long)]
+    paused: bool,
+    /// Write every frame as plain text to this file
+    #[arg(long)]
+    record: Option<PathBuf>,
+    /// Only record the frames instead of showing them
+    #[arg(long, requires = "record")]
+    no_display: bool,
+}
+
+pub fn animate(args: AnimateArgs) -> Result<(), String> {
+    let day = days::find(args.day).ok_or_else(|| format!("day {} is not solved yet", args.day))?;
+    let input = input::load(day.day, args.input.as_ref())?;
+    let mut animation = Animation::new(Options {
+        fps: args.fps,
+        colors: !args.no_color,
+        paused: args.paused,
+        display: !args.no_display,
+        record: args.record.clone(),
+    })
+    .map_err(|e| format!("could not start the animation: {e}"))?;
+    day.animate(&input, &mut animation)
+        .map_err(|e| format!("could not animate day {}: {e}", day.day))?;
+    match (animation.frames(), args.record) {
+        (0, _) => Err(format!("day {} has no animation", day.day)),
+        (frames, Some(path)) => {
+            println!("{frames} frames recorded to {}", path.display());
+            Ok(())
+        }
+        (_, None) => Ok(()),
+    }
+}
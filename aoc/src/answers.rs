@@ -0,0 +1,267 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::solution::Part;
+use serde::{Deserialize, Serialize};
+
+/// Recorded answers, keyed by day, input hash and part. Parts without a correct answer yet
+/// keep track of the answers the puzzle server rejected instead:
+///
+/// ```toml
+/// [day14.2f7d6e1a0b3c4d5e]
+/// part1 = "136"
+///
+/// [day14.2f7d6e1a0b3c4d5e.part2]
+/// wrong = ["65"]
+/// too_low = "60"
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Answers(BTreeMap<String, BTreeMap<String, BTreeMap<String, Record>>>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Record {
+    Answer(String),
+    Rejected(Rejected),
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Rejected {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    wrong: Vec<String>,
+    /// The highest answer that was too low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    too_low: Option<String>,
+    /// The lowest answer that was too high.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    too_high: Option<String>,
+}
+
+/// Why the puzzle server rejected an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    Wrong,
+    TooLow,
+    TooHigh,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hint::Wrong => write!(f, "not the right answer"),
+            Hint::TooLow => write!(f, "too low"),
+            Hint::TooHigh => write!(f, "too high"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    /// Known to be wrong from earlier submissions, though the correct answer is unknown.
+    Rejected(Hint),
+    New,
+}
+
+/// Compares two answers numerically, if both are numbers.
+fn compare(a: &str, b: &str) -> Option<Ordering> {
+    Some(a.parse::<i128>().ok()?.cmp(&b.parse().ok()?))
+}
+
+impl Rejected {
+    fn check(&self, answer: &str) -> Option<Hint> {
+        if self.wrong.iter().any(|wrong| wrong == answer) {
+            Some(Hint::Wrong)
+        } else if (self.too_low.as_ref())
+            .and_then(|low| compare(answer, low))
+            .is_some_and(Ordering::is_le)
+        {
+            Some(Hint::TooLow)
+        } else if (self.too_high.as_ref())
+            .and_then(|high| compare(answer, high))
+            .is_some_and(Ordering::is_ge)
+        {
+            Some(Hint::TooHigh)
+        } else {
+            None
+        }
+    }
+
+    fn add(&mut self, answer: &str, hint: Hint) {
+        let (bound, keep) = match hint {
+            Hint::Wrong => {
+                self.wrong.push(answer.to_string());
+                return;
+            }
+            Hint::TooLow => (&mut self.too_low, Ordering::Greater),
+            Hint::TooHigh => (&mut self.too_high, Ordering::Less),
+        };
+        match bound {
+            Some(old) if compare(answer, old) != Some(keep) => {}
+            _ => *bound = Some(answer.to_string()),
+        }
+    }
+}
+
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: Answers,
+}
+
+/// The `answers.toml` file in the workspace root.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.toml")
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day}")
+}
+
+fn part_key(part: Part) -> String {
+    format!("part{part}")
+}
+
+impl AnswerStore {
+    /// Loads the store at `path`, starting out empty if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let answers = match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| format!("could not parse {}: {e}", path.display()))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Answers::default(),
+            Err(e) => return Err(format!("could not read {}: {e}", path.display())),
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            answers,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let content = toml::to_string(&self.answers)
+            .map_err(|e| format!("could not serialize the answers: {e}"))?;
+        fs::write(&self.path, content)
+            .map_err(|e| format!("could not write {}: {e}", self.path.display()))
+    }
+
+    fn entry(&self, day: u8, input_hash: &str, part: Part) -> Option<&Record> {
+        self.answers
+            .0
+            .get(&day_key(day))?
+            .get(input_hash)?
+            .get(&part_key(part))
+    }
+
+    fn entry_mut(&mut self, day: u8, input_hash: &str, part: Part) -> &mut Record {
+        self.answers
+            .0
+            .entry(day_key(day))
+            .or_default()
+            .entry(input_hash.to_string())
+            .or_default()
+            .entry(part_key(part))
+            .or_insert_with(|| Record::Rejected(Rejected::default()))
+    }
+
+    pub fn check(&self, day: u8, input_hash: &str, part: Part, answer: &str) -> Verdict {
+        match self.entry(day, input_hash, part) {
+            None => Verdict::New,
+            Some(Record::Answer(expected)) if expected == answer => Verdict::Correct,
+            Some(Record::Answer(expected)) => Verdict::Wrong {
+                expected: expected.clone(),
+            },
+            Some(Record::Rejected(rejected)) => rejected
+                .check(answer)
+                .map_or(Verdict::New, Verdict::Rejected),
+        }
+    }
+
+    pub fn record(&mut self, day: u8, input_hash: &str, part: Part, answer: &str) {
+        *self.entry_mut(day, input_hash, part) = Record::Answer(answer.to_string());
+    }
+
+    /// Remembers an answer the puzzle server rejected, unless the correct one is known.
+    pub fn reject(&mut self, day: u8, input_hash: &str, part: Part, answer: &str, hint: Hint) {
+        if let Record::Rejected(rejected) = self.entry_mut(day, input_hash, part) {
+            rejected.add(answer, hint);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_recorded_answers() {
+        let mut store = AnswerStore {
+            path: PathBuf::new(),
+            answers: Answers::default(),
+        };
+        assert_eq!(store.check(14, "abc", Part::One, "136"), Verdict::New);
+        store.record(14, "abc", Part::One, "136");
+        assert_eq!(store.check(14, "abc", Part::One, "136"), Verdict::Correct);
+        assert_eq!(
+            store.check(14, "abc", Part::One, "135"),
+            Verdict::Wrong {
+                expected: "136".to_string()
+            }
+        );
+        assert_eq!(store.check(14, "abc", Part::Two, "64"), Verdict::New);
+        assert_eq!(store.check(14, "def", Part::One, "136"), Verdict::New);
+    }
+
+    #[test]
+    fn round_trip_through_toml() {
+        let mut store = AnswerStore {
+            path: PathBuf::new(),
+            answers: Answers::default(),
+        };
+        store.record(5, "abc", Part::Two, "46");
+        let content = toml::to_string(&store.answers).unwrap();
+        let answers: Answers = toml::from_str(&content).unwrap();
+        assert_eq!(
+            answers.0["day5"]["abc"]["part2"],
+            Record::Answer("46".to_string())
+        );
+    }
+
+    #[test]
+    fn check_rejected_answers() {
+        let mut store = AnswerStore {
+            path: PathBuf::new(),
+            answers: Answers::default(),
+        };
+        store.reject(5, "abc", Part::Two, "40", Hint::TooLow);
+        store.reject(5, "abc", Part::Two, "30", Hint::TooLow);
+        store.reject(5, "abc", Part::Two, "50", Hint::TooHigh);
+        store.reject(5, "abc", Part::Two, "45", Hint::Wrong);
+        let check = |answer| store.check(5, "abc", Part::Two, answer);
+        assert_eq!(check("40"), Verdict::Rejected(Hint::TooLow));
+        assert_eq!(check("50"), Verdict::Rejected(Hint::TooHigh));
+        assert_eq!(check("45"), Verdict::Rejected(Hint::Wrong));
+        assert_eq!(check("46"), Verdict::New);
+
+        let content = toml::to_string(&store.answers).unwrap();
+        let answers: Answers = toml::from_str(&content).unwrap();
+        assert_eq!(answers.0, store.answers.0);
+
+        store.record(5, "abc", Part::Two, "46");
+        store.reject(5, "abc", Part::Two, "47", Hint::TooHigh);
+        assert_eq!(store.check(5, "abc", Part::Two, "46"), Verdict::Correct);
+    }
+}
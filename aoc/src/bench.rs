@@ -0,0 +1,193 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_common::solution::Timings;
+use clap::Args;
+use serde::Serialize;
+
+use crate::{input, Selection};
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Day number or `all`
+    day: Selection,
+    /// How often each day is parsed and solved
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+    /// Where to write the JSON report, `target/bench.json` in the workspace root by default
+    #[arg(long)]
+    report: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+struct Stats {
+    mean_ns: u64,
+    min_ns: u64,
+}
+
+impl Stats {
+    fn from_durations(durations: &[Duration]) -> Option<Self> {
+        let min = durations.iter().min()?;
+        let total: Duration = durations.iter().sum();
+        Some(Self {
+            mean_ns: (total / durations.len() as u32).as_nanos() as u64,
+            min_ns: min.as_nanos() as u64,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct DayReport {
+    day: u8,
+    parse: Stats,
+    part1: Stats,
+    part2: Option<Stats>,
+}
+
+impl DayReport {
+    fn new(day: u8, timings: &Timings) -> Option<Self> {
+        Some(Self {
+            day,
+            parse: Stats::from_durations(&timings.parse)?,
+            part1: Stats::from_durations(&timings.part1)?,
+            part2: Stats::from_durations(&timings.part2),
+        })
+    }
+
+    fn total_mean_ns(&self) -> u64 {
+        self.parse.mean_ns + self.part1.mean_ns + self.part2.map_or(0, |s| s.mean_ns)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    iterations: u32,
+    days: Vec<DayReport>,
+}
+
+fn default_report_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("target")
+        .join("bench.json")
+}
+
+fn format_ns(ns: u64) -> String {
+    match ns {
+        0..=999 => format!("{ns} ns"),
+        1_000..=999_999 => format!("{:.2} µs", ns as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2} ms", ns as f64 / 1e6),
+        _ => format!("{:.2} s", ns as f64 / 1e9),
+    }
+}
+
+fn format_stats(stats: Option<Stats>) -> [String; 2] {
+    match stats {
+        Some(stats) => [format_ns(stats.mean_ns), format_ns(stats.min_ns)],
+        None => ["-".to_string(), "-".to_string()],
+    }
+}
+
+fn print_table(days: &[DayReport]) {
+    let header = [
+        "Day",
+        "Parse mean",
+        "Parse min",
+        "Part 1 mean",
+        "Part 1 min",
+        "Part 2 mean",
+        "Part 2 min",
+        "Total mean",
+    ];
+    let rows: Vec<Vec<String>> = days
+        .iter()
+        .map(|day| {
+            let mut row = vec![day.day.to_string()];
+            row.extend(format_stats(Some(day.parse)));
+            row.extend(format_stats(Some(day.part1)));
+            row.extend(format_stats(day.part2));
+            row.push(format_ns(day.total_mean_ns()));
+            row
+        })
+        .collect();
+    let widths: Vec<usize> = (0..header.len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .chain([header[col].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let format_row = |cells: Vec<String>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:>width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    };
+    println!("{}", format_row(header.map(String::from).to_vec()));
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-|-")
+    );
+    for row in rows {
+        println!("{}", format_row(row));
+    }
+}
+
+pub fn bench(args: BenchArgs) -> Result<(), String> {
+    let mut days = vec![];
+    for day in args.day.days()? {
+        let input = input::load(day.day, None)?;
+        let timings = day
+            .bench(&input, args.iterations as usize)
+            .map_err(|e| format!("could not solve day {}: {e}", day.day))?;
+        days.extend(DayReport::new(day.day, &timings));
+    }
+    print_table(&days);
+
+    let report = Report {
+        iterations: args.iterations,
+        days,
+    };
+    let path = args.report.unwrap_or_else(default_report_path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("could not create {}: {e}", parent.display()))?;
+    }
+    let content = serde_json::to_string_pretty(&report)
+        .map_err(|e| format!("could not serialize the report: {e}"))?;
+    fs::write(&path, content).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    println!("\nReport written to {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_durations() {
+        let durations = [2, 4, 9].map(Duration::from_nanos);
+        let stats = Stats::from_durations(&durations).unwrap();
+        assert_eq!((stats.mean_ns, stats.min_ns), (5, 2));
+        assert!(Stats::from_durations(&[]).is_none());
+    }
+
+    #[test]
+    fn formatted_durations() {
+        assert_eq!(format_ns(999), "999 ns");
+        assert_eq!(format_ns(1_500), "1.50 µs");
+        assert_eq!(format_ns(2_345_678), "2.35 ms");
+        assert_eq!(format_ns(3_000_000_000), "3.00 s");
+    }
+}
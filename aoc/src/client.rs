@@ -0,0 +1,287 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::solution::Part;
+use clap::Args;
+
+use crate::answers::Hint;
+
+const USER_AGENT: &str = "adventofcode2023 runner (github.com/Gobidev/adventofcode2023)";
+
+#[derive(Args)]
+pub struct ClientArgs {
+    /// Base URL of the puzzle server
+    #[arg(long, env = "AOC_URL", default_value = "https://adventofcode.com")]
+    url: String,
+    /// Year of the puzzles
+    #[arg(long, env = "AOC_YEAR", default_value_t = 2023)]
+    year: u16,
+    /// Value of the `session` cookie to log in with
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// Minimum number of seconds between two requests to the server
+    #[arg(long, default_value_t = 5)]
+    delay: u64,
+}
+
+/// What the puzzle server said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    Rejected(Hint),
+    /// Submitted too soon after the previous answer.
+    Wait(Duration),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    url: String,
+    year: u16,
+    session: String,
+    delay: Duration,
+    /// Holds the earliest time of the next request, shared between runs of the binary.
+    state_path: PathBuf,
+}
+
+fn default_state_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("target")
+        .join("aoc-next-request")
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+impl Client {
+    pub fn new(args: ClientArgs) -> Result<Self, String> {
+        let session = args.session.ok_or(
+            "no session token, pass `--session` or set `AOC_SESSION` to your `session` cookie",
+        )?;
+        Ok(Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            url: args.url.trim_end_matches('/').to_string(),
+            year: args.year,
+            session,
+            delay: Duration::from_secs(args.delay),
+            state_path: default_state_path(),
+        })
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        self.throttle(self.delay)?;
+        self.agent
+            .get(&format!("{}/{}/day/{day}/input", self.url, self.year))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| format!("could not download the input of day {day}: {e}"))?
+            .into_string()
+            .map_err(|e| format!("could not read the input of day {day}: {e}"))
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Response, String> {
+        self.throttle(self.delay)?;
+        let body = self
+            .agent
+            .post(&format!("{}/{}/day/{day}/answer", self.url, self.year))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| format!("could not submit the answer of day {day}: {e}"))?
+            .into_string()
+            .map_err(|e| format!("could not read the response for day {day}: {e}"))?;
+        let response = parse_response(&body)?;
+        if let Response::Wait(wait) = response {
+            self.throttle(wait)?;
+        }
+        Ok(response)
+    }
+
+    /// Waits until the next request is allowed and blocks further ones for `delay`.
+    fn throttle(&self, delay: Duration) -> Result<(), String> {
+        let next_request = match fs::read_to_string(&self.state_path) {
+            Ok(content) => content.trim().parse().map(Duration::from_millis).ok(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(format!("could not read {}: {e}", self.state_path.display())),
+        };
+        if let Some(wait) = next_request.and_then(|next| next.checked_sub(now())) {
+            eprintln!("waiting {:.1}s before the next request", wait.as_secs_f64());
+            thread::sleep(wait);
+        }
+        if let Some(parent) = self.state_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("could not create {}: {e}", parent.display()))?;
+        }
+        fs::write(&self.state_path, (now() + delay).as_millis().to_string())
+            .map_err(|e| format!("could not write {}: {e}", self.state_path.display()))
+    }
+}
+
+/// Parses a wait time like `1m 5s` from `You have 1m 5s left to wait`.
+fn parse_wait(body: &str) -> Option<Duration> {
+    let (_, rest) = body.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .try_fold(Duration::ZERO, |total, amount| {
+            let (number, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let seconds = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(number.parse::<u64>().ok()? * seconds))
+        })
+}
+
+fn parse_response(body: &str) -> Result<Response, String> {
+    if body.contains("That's the right answer") {
+        Ok(Response::Correct)
+    } else if body.contains("That's not the right answer") {
+        Ok(Response::Rejected(
+            if body.contains("your answer is too low") {
+                Hint::TooLow
+            } else if body.contains("your answer is too high") {
+                Hint::TooHigh
+            } else {
+                Hint::Wrong
+            },
+        ))
+    } else if body.contains("You gave an answer too recently") {
+        parse_wait(body)
+            .map(Response::Wait)
+            .ok_or_else(|| "could not read the wait time from the response".to_string())
+    } else if body.contains("You don't seem to be solving the right level") {
+        Ok(Response::WrongLevel)
+    } else {
+        Err("could not understand the response of the server".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::JoinHandle,
+    };
+
+    use super::*;
+
+    /// Serves one canned response per request and hands back the requests it received.
+    fn serve(responses: &[&'static str]) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let responses = responses.to_vec();
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|body| {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some((name, value)) = line.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                        }
+                        request += &line;
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+                    let mut content = vec![0; content_length];
+                    reader.read_exact(&mut content).unwrap();
+                    request += &String::from_utf8(content).unwrap();
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+        (url, handle)
+    }
+
+    fn client(url: String, name: &str) -> Client {
+        let state_path = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_file(&state_path);
+        Client {
+            state_path,
+            ..Client::new(ClientArgs {
+                url,
+                year: 2023,
+                session: Some("cookie".to_string()),
+                delay: 0,
+            })
+            .unwrap()
+        }
+    }
+
+    #[test]
+    fn fetch_input() {
+        let (url, server) = serve(&["0 3 6 9 12 15\n"]);
+        let input = client(url, "fetch").input(9).unwrap();
+        assert_eq!(input, "0 3 6 9 12 15\n");
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2023/day/9/input "));
+        assert!(requests[0].contains("session=cookie"));
+    }
+
+    #[test]
+    fn submit_answers() {
+        let (url, server) = serve(&[
+            "<p>That's not the right answer; your answer is too low.</p>",
+            "<p>That's the right answer!</p>",
+        ]);
+        let client = client(url, "submit");
+        assert_eq!(
+            client.submit(6, Part::Two, "71502").unwrap(),
+            Response::Rejected(Hint::TooLow)
+        );
+        assert_eq!(
+            client.submit(6, Part::Two, "71503").unwrap(),
+            Response::Correct
+        );
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2023/day/6/answer "));
+        assert!(requests[0].ends_with("level=2&answer=71502"));
+    }
+
+    #[test]
+    fn parse_responses() {
+        assert_eq!(
+            parse_response("That's not the right answer. If you're stuck, ...").unwrap(),
+            Response::Rejected(Hint::Wrong)
+        );
+        assert_eq!(
+            parse_response("That's not the right answer; your answer is too high.").unwrap(),
+            Response::Rejected(Hint::TooHigh)
+        );
+        assert_eq!(
+            parse_response("You gave an answer too recently. You have 1m 5s left to wait.")
+                .unwrap(),
+            Response::Wait(Duration::from_secs(65))
+        );
+        assert_eq!(
+            parse_response("You don't seem to be solving the right level.").unwrap(),
+            Response::WrongLevel
+        );
+        assert!(parse_response("<html>Puzzle inputs differ by user.</html>").is_err());
+    }
+}
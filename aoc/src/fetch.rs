@@ -0,0 +1,36 @@
+use std::fs;
+
+use clap::Args;
+
+use crate::{
+    client::{Client, ClientArgs},
+    input,
+};
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Day number
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Download the input again even if `dayN/input.txt` already exists
+    #[arg(long)]
+    force: bool,
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+pub fn fetch(args: FetchArgs) -> Result<(), String> {
+    let path = input::default_path(args.day);
+    if path.exists() && !args.force {
+        println!("Day {:>2}: input already at {}", args.day, path.display());
+        return Ok(());
+    }
+    let input = Client::new(args.client)?.input(args.day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("could not create {}: {e}", parent.display()))?;
+    }
+    fs::write(&path, input).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    println!("Day {:>2}: input saved to {}", args.day, path.display());
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use std::{
+    any::Any,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::{
+    generate::{Options, Rng},
+    solution::{Day, Part},
+};
+use clap::{builder::RangedU64ValueParser, Args};
+
+use crate::days;
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Day number
+    day: u8,
+    /// Seed of the generator, a random one is picked and printed by default
+    #[arg(long)]
+    seed: Option<u64>,
+    /// How large the input is, what exactly this counts depends on the day
+    #[arg(
+        long,
+        default_value_t = Options::default().size,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..),
+    )]
+    size: usize,
+    /// How full the input is, between 0 and 1
+    #[arg(long, default_value_t = Options::default().density)]
+    density: f64,
+    /// Write the input to this file instead of stdout
+    #[arg(short, long, conflicts_with = "stress")]
+    output: Option<PathBuf>,
+    /// Instead of printing an input, solve this many inputs generated from consecutive seeds and
+    /// report the seeds whose input could not be parsed or made a part panic
+    #[arg(long)]
+    stress: Option<u64>,
+}
+
+pub fn generate(args: GenerateArgs) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&args.density) {
+        return Err(format!(
+            "the density must be between 0 and 1, got {}",
+            args.density
+        ));
+    }
+    let day = days::find(args.day).ok_or_else(|| format!("day {} is not solved yet", args.day))?;
+    let options = Options {
+        size: args.size,
+        density: args.density,
+    };
+    let seed = args.seed.unwrap_or_else(|| {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |t| t.as_nanos() as u64);
+        eprintln!("seed: {seed}");
+        seed
+    });
+    let generate = |seed| {
+        day.generate(&mut Rng::new(seed), &options)
+            .ok_or_else(|| format!("day {} has no input generator", day.day))
+    };
+
+    let Some(count) = args.stress else {
+        let input = generate(seed)?;
+        return match args.output {
+            Some(path) => fs::write(&path, input)
+                .map_err(|e| format!("could not write {}: {e}", path.display())),
+            None => {
+                print!("{input}");
+                Ok(())
+            }
+        };
+    };
+
+    // the failing seeds are reported below, without the default hook's message for every panic
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut failures = 0;
+    for seed in seed..seed.saturating_add(count) {
+        let input = generate(seed)?;
+        if let Err(e) = solve(day, &input) {
+            println!("Day {:>2} seed {seed}: {e}", day.day);
+            failures += 1;
+        }
+    }
+    panic::set_hook(default_hook);
+    match failures {
+        0 => {
+            println!("Day {:>2}: solved {count} generated inputs", day.day);
+            Ok(())
+        }
+        n => Err(format!("{n} of {count} generated inputs failed")),
+    }
+}
+
+fn solve(day: &Day, input: &str) -> Result<(), String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| day.run(input, &[Part::One, Part::Two]))) {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        args: GenerateArgs,
+    }
+
+    #[test]
+    fn size_is_at_least_one() {
+        assert!(Cli::try_parse_from(["generate", "2", "--size", "0"]).is_err());
+        let cli = Cli::try_parse_from(["generate", "2", "--size", "1"]).unwrap();
+        assert_eq!(cli.args.size, 1);
+    }
+
+    #[test]
+    fn smallest_inputs_are_solved() {
+        for day in days::DAYS.iter() {
+            for density in [0.0, 0.5, 1.0] {
+                let options = Options { size: 1, density };
+                for seed in 0..20 {
+                    let Some(input) = day.generate(&mut Rng::new(seed), &options) else {
+                        continue;
+                    };
+                    if let Err(e) = solve(day, &input) {
+                        panic!("day {} seed {seed} density {density}: {e}", day.day);
+                    }
+                }
+            }
+        }
+    }
+}
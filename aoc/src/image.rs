@@ -0,0 +1,264 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs::{self, File},
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{
+    drawing::{bounds, rasterize, Drawing, Rgb, POLYGON_EDGE, POLYGON_FILL},
+    geometry::Point,
+    grid::Grid,
+};
+use clap::{Args, ValueEnum};
+
+use crate::{
+    days,
+    input::{self, Source},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Png,
+    Svg,
+    Gif,
+}
+
+#[derive(Args)]
+pub struct ImageArgs {
+    /// Day number
+    day: u8,
+    /// Read the input from this file, or `-` for stdin, instead of `dayN/input.txt`
+    #[arg(short, long)]
+    input: Option<Source>,
+    /// File to write the image to
+    #[arg(short, long)]
+    output: PathBuf,
+    /// Image format, guessed from the output's extension by default
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+    /// Pixels per tile
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64))]
+    scale: u32,
+    /// Time between the frames of a GIF, in hundredths of a second
+    #[arg(long, default_value_t = 10)]
+    delay: u16,
+}
+
+pub fn image(args: ImageArgs) -> Result<(), String> {
+    let format = match args.format {
+        Some(format) => format,
+        None => guess_format(&args.output)?,
+    };
+    let day = days::find(args.day).ok_or_else(|| format!("day {} is not solved yet", args.day))?;
+    let input = input::load(day.day, args.input.as_ref())?;
+    let drawing = day
+        .draw(&input)
+        .map_err(|e| format!("could not draw day {}: {e}", day.day))?
+        .ok_or_else(|| format!("day {} has no drawing", day.day))?;
+
+    let written = match format {
+        Format::Svg => {
+            let svg = match &drawing {
+                Drawing::Polygon(points) => polygon_svg(points),
+                Drawing::Grid(grid) => grid_svg(grid, args.scale),
+                // an SVG shows the final frame only
+                Drawing::Frames(frames) => grid_svg(last_frame(frames)?, args.scale),
+            };
+            fs::write(&args.output, svg).map_err(|e| e.to_string())
+        }
+        Format::Png => {
+            let grid = match &drawing {
+                Drawing::Polygon(points) => &rasterize(points),
+                Drawing::Grid(grid) => grid,
+                Drawing::Frames(frames) => last_frame(frames)?,
+            };
+            write_png(&args.output, grid, args.scale)
+        }
+        Format::Gif => write_gif(&args.output, &drawing.into_frames(), args.scale, args.delay),
+    };
+    written.map_err(|e| format!("could not write {}: {e}", args.output.display()))?;
+    println!("Day {:>2}: drawn to {}", day.day, args.output.display());
+    Ok(())
+}
+
+fn guess_format(path: &Path) -> Result<Format, String> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default();
+    match extension.to_ascii_lowercase().as_str() {
+        "png" => Ok(Format::Png),
+        "svg" => Ok(Format::Svg),
+        "gif" => Ok(Format::Gif),
+        _ => Err(format!(
+            "cannot tell the image format of {}, pass `--format`",
+            path.display()
+        )),
+    }
+}
+
+fn last_frame(frames: &[Grid<Rgb>]) -> Result<&Grid<Rgb>, String> {
+    frames
+        .last()
+        .ok_or_else(|| "the drawing has no frames".to_string())
+}
+
+/// The grid's pixels as RGB bytes, every tile `scale` pixels wide and high.
+fn pixels(grid: &Grid<Rgb>, scale: u32) -> Vec<u8> {
+    let scale = scale as usize;
+    let mut res = Vec::with_capacity(grid.width() * grid.height() * scale * scale * 3);
+    for row in grid.rows() {
+        for _ in 0..scale {
+            for color in row {
+                for _ in 0..scale {
+                    res.extend([color.0, color.1, color.2]);
+                }
+            }
+        }
+    }
+    res
+}
+
+fn write_png(path: &Path, grid: &Grid<Rgb>, scale: u32) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| e.to_string())?;
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(file),
+        grid.width() as u32 * scale,
+        grid.height() as u32 * scale,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+    writer
+        .write_image_data(&pixels(grid, scale))
+        .map_err(|e| e.to_string())
+}
+
+fn write_gif(path: &Path, frames: &[Grid<Rgb>], scale: u32, delay: u16) -> Result<(), String> {
+    let first = frames
+        .first()
+        .ok_or_else(|| "the drawing has no frames".to_string())?;
+    let (width, height) = (first.width() as u32 * scale, first.height() as u32 * scale);
+    let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(format!("{width}x{height} pixels is too large for a GIF"));
+    };
+
+    // most drawings use few colours, which fit a single palette shared by all frames
+    let mut palette: HashMap<Rgb, u8> = HashMap::new();
+    for color in frames.iter().flat_map(Grid::cells) {
+        if palette.len() > 256 {
+            break;
+        }
+        let idx = palette.len() as u8;
+        palette.entry(*color).or_insert(idx);
+    }
+    let shared = palette.len() <= 256;
+    let mut global_palette = vec![0; palette.len() * 3];
+    for (color, idx) in &palette {
+        let idx = *idx as usize * 3;
+        global_palette[idx..idx + 3].copy_from_slice(&[color.0, color.1, color.2]);
+    }
+
+    let file = File::create(path).map_err(|e| e.to_string())?;
+    let global_palette = if shared { &global_palette[..] } else { &[] };
+    let mut encoder = gif::Encoder::new(BufWriter::new(file), width, height, global_palette)
+        .map_err(|e| e.to_string())?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(|e| e.to_string())?;
+    for grid in frames {
+        if grid.width() != first.width() || grid.height() != first.height() {
+            return Err("the frames of a GIF must all have the same size".to_string());
+        }
+        let mut frame = if shared {
+            let indices = pixels(grid, scale)
+                .chunks(3)
+                .map(|c| palette[&Rgb(c[0], c[1], c[2])])
+                .collect();
+            gif::Frame {
+                width,
+                height,
+                buffer: Cow::Owned(indices),
+                ..gif::Frame::default()
+            }
+        } else {
+            gif::Frame::from_rgb_speed(width, height, &pixels(grid, scale), 10)
+        };
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn grid_svg(grid: &Grid<Rgb>, scale: u32) -> String {
+    let (width, height) = (grid.width() as u32 * scale, grid.height() as u32 * scale);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+        grid.width(),
+        grid.height()
+    );
+    for (y, row) in grid.rows().enumerate() {
+        // one rectangle per run of equally coloured tiles
+        let mut x = 0;
+        for run in row.chunk_by(|a, b| a == b) {
+            svg += &format!(
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{}\" height=\"1\" fill=\"{}\"/>\n",
+                run.len(),
+                run[0].hex()
+            );
+            x += run.len();
+        }
+    }
+    svg + "</svg>\n"
+}
+
+fn polygon_svg(points: &[Point]) -> String {
+    let (min, max) = bounds(points);
+    let (width, height) = (max.col - min.col + 1, max.row - min.row + 1);
+    let size = 1000.0 / width.max(height) as f64;
+    let corners: Vec<String> = points
+        .iter()
+        .map(|p| format!("{},{}", p.col, p.row))
+        .collect();
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" height=\"{:.0}\" \
+         viewBox=\"{} {} {width} {height}\">\n\
+         <rect x=\"{}\" y=\"{}\" width=\"{width}\" height=\"{height}\" fill=\"{}\"/>\n\
+         <polygon points=\"{}\" fill=\"{}\" stroke=\"{}\" stroke-width=\"2\" \
+         vector-effect=\"non-scaling-stroke\"/>\n</svg>\n",
+        width as f64 * size,
+        height as f64 * size,
+        min.col as f64 - 0.5,
+        min.row as f64 - 0.5,
+        min.col as f64 - 0.5,
+        min.row as f64 - 0.5,
+        Rgb::BACKGROUND.hex(),
+        corners.join(" "),
+        POLYGON_FILL.hex(),
+        POLYGON_EDGE.hex(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn svg_merges_runs() {
+        let grid = Grid::new(3, 1, vec![Rgb::BLACK, Rgb::BLACK, Rgb::WHITE]);
+        let svg = grid_svg(&grid, 2);
+        assert!(svg.contains("width=\"6\" height=\"2\" viewBox=\"0 0 3 1\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#000000\"/>"));
+        assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"1\" height=\"1\" fill=\"#ffffff\"/>"));
+    }
+
+    #[test]
+    fn format_from_extension() {
+        assert_eq!(guess_format(Path::new("day16.PNG")), Ok(Format::Png));
+        assert_eq!(guess_format(Path::new("out/day18.svg")), Ok(Format::Svg));
+        assert!(guess_format(Path::new("day21")).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+mod animate;
+mod answers;
+mod bench;
+mod client;
+mod days;
+mod fetch;
+mod generate;
+mod image;
+mod input;
+mod new;
+mod repl;
+mod run;
+mod submit;
+mod watch;
+
+use std::{path::PathBuf, process::ExitCode, str::FromStr};
+
+use answers::AnswerStore;
+use aoc_common::{
+    parallel,
+    solution::{Day, Part},
+};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    /// Answer store to check results against, `answers.toml` in the workspace root by default
+    #[arg(long, global = true)]
+    answers: Option<PathBuf>,
+    /// Threads to solve days and parts with, one per CPU by default
+    #[arg(short, long, global = true, env = "AOC_JOBS")]
+    jobs: Option<usize>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day or `all` days
+    Run(run::RunArgs),
+    /// Show a day's solution at work in the terminal
+    Animate(animate::AnimateArgs),
+    /// Export a picture of a day's solution as a PNG, SVG or GIF image
+    Image(image::ImageArgs),
+    /// Time parsing and both parts of a single day or `all` days
+    Bench(bench::BenchArgs),
+    /// Download a day's puzzle input to `dayN/input.txt`
+    Fetch(fetch::FetchArgs),
+    /// Submit an answer and record the verdict in the answer store
+    Submit(submit::SubmitArgs),
+    /// Create a crate for a new day and register it with the runner
+    New(new::NewArgs),
+    /// Solve a day and run its tests again whenever its source or input changes
+    Watch(watch::WatchArgs),
+    /// Generate a random input for a day, or stress test a day with many of them
+    Generate(generate::GenerateArgs),
+    /// Explore a day's parsed input with the day's commands
+    Repl(repl::ReplArgs),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+        s.parse()
+            .map(Selection::Day)
+            .map_err(|_| format!("expected a day number or `all`, got `{s}`"))
+    }
+}
+
+impl Selection {
+    pub fn days(&self) -> Result<Vec<&'static Day>, String> {
+        match self {
+            Selection::All => Ok(days::DAYS.iter().collect()),
+            Selection::Day(day) => days::find(*day)
+                .map(|day| vec![day])
+                .ok_or_else(|| format!("day {day} is not solved yet")),
+        }
+    }
+}
+
+pub fn parts(part: Option<u8>) -> Vec<Part> {
+    match part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => vec![Part::One, Part::Two],
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = cli
+        .jobs
+        .map_or(Ok(()), parallel::set_threads)
+        .and_then(|()| AnswerStore::load(&cli.answers.unwrap_or_else(answers::default_path)))
+        .and_then(|mut store| match cli.command {
+            Command::Run(args) => run::run(args, &mut store),
+            Command::Animate(args) => animate::animate(args),
+            Command::Image(args) => image::image(args),
+            Command::Bench(args) => bench::bench(args),
+            Command::Fetch(args) => fetch::fetch(args),
+            Command::Submit(args) => submit::submit(args, &mut store),
+            Command::New(args) => new::new(args),
+            Command::Generate(args) => generate::generate(args),
+            Command::Watch(args) => watch::watch(args, &store),
+            Command::Repl(args) => repl::repl(args),
+        });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
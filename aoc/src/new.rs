@@ -0,0 +1,176 @@
+use std::{fs, path::Path};
+
+use clap::Args;
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+const FUZZ_TEMPLATE: &str = include_str!("../templates/fuzz_target.rs.template");
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// Day number
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+pub fn new(args: NewArgs) -> Result<(), String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    scaffold(&root, args.day)?;
+    println!(
+        "Day {:>2}: created {} and registered it with the runner",
+        args.day,
+        root.join(format!("day{}", args.day)).display()
+    );
+    Ok(())
+}
+
+/// Creates the `dayN` crate in the workspace at `root` and adds it to the workspace members,
+/// the runner's dependencies and its list of days, which the web build shares, and gives it a
+/// fuzz target.
+fn scaffold(root: &Path, day: u8) -> Result<(), String> {
+    let dir = root.join(format!("day{day}"));
+    // the directory itself may already exist if the input was fetched first
+    if dir.join("Cargo.toml").exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+    for (path, template) in [
+        (dir.join("Cargo.toml"), CARGO_TEMPLATE),
+        (dir.join("src").join("lib.rs"), LIB_TEMPLATE),
+    ] {
+        fs::write(&path, template.replace("{day}", &day.to_string()))
+            .map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    }
+
+    register(&root.join("Cargo.toml"), day, |d| {
+        format!("    \"day{d}\",")
+    })?;
+    register(&root.join("aoc").join("Cargo.toml"), day, |d| {
+        format!("day{d} = {{ path = \"../day{d}\" }}")
+    })?;
+    register(&root.join("aoc").join("src").join("days.rs"), day, |d| {
+        format!("    Day::of::<day{d}::Day{d}>(),")
+    })?;
+    let web = root.join("web").join("Cargo.toml");
+    if web.exists() {
+        register(&web, day, |d| {
+            format!("day{d} = {{ path = \"../day{d}\" }}")
+        })?;
+    }
+
+    let fuzz = root.join("fuzz");
+    if !fuzz.exists() {
+        return Ok(());
+    }
+    let target = fuzz.join("fuzz_targets").join(format!("day{day}.rs"));
+    fs::write(&target, FUZZ_TEMPLATE.replace("{day}", &day.to_string()))
+        .map_err(|e| format!("could not write {}: {e}", target.display()))?;
+    register(&fuzz.join("Cargo.toml"), day, |d| {
+        format!("day{d} = {{ path = \"../day{d}\" }}")
+    })?;
+    register(&fuzz.join("Cargo.toml"), day, |d| {
+        format!(
+            "    {{ name = \"day{d}\", path = \"fuzz_targets/day{d}.rs\", \
+             test = false, doc = false, bench = false }},"
+        )
+    })
+}
+
+/// Inserts the line `line` generates for `day` among the lines it generates for the other days,
+/// keeping them ordered by day.
+fn register(path: &Path, day: u8, line: impl Fn(u8) -> String) -> Result<(), String> {
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))?;
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let registered: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, l)| (1..=25).find(|d| *l == line(*d)).map(|d| (idx, d)))
+        .collect();
+    let Some(&(last_idx, _)) = registered.last() else {
+        return Err(format!(
+            "could not find where to register day {day} in {}",
+            path.display()
+        ));
+    };
+    if registered.iter().any(|(_, d)| *d == day) {
+        return Ok(());
+    }
+    let idx = registered
+        .iter()
+        .find(|(_, d)| *d > day)
+        .map_or(last_idx + 1, |(idx, _)| *idx);
+    lines.insert(idx, line(day));
+    fs::write(path, lines.join("\n") + "\n")
+        .map_err(|e| format!("could not write {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn scaffold_registers_in_order() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::create_dir_all(root.join("fuzz").join("fuzz_targets")).unwrap();
+        fs::create_dir_all(root.join("web")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day16\",\n    \"day18\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\nday16 = { path = \"../day16\" }\nday18 = { path = \"../day18\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("days.rs"),
+            "pub const DAYS: &[Day] = &[\n    Day::of::<day16::Day16>(),\n];\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("web").join("Cargo.toml"),
+            "[dependencies]\nday16 = { path = \"../day16\" }\n",
+        )
+        .unwrap();
+
+        fs::write(
+            root.join("fuzz").join("Cargo.toml"),
+            "bin = [\n    { name = \"day16\", path = \"fuzz_targets/day16.rs\", test = false, \
+             doc = false, bench = false },\n]\n\n[dependencies]\nday16 = { path = \"../day16\" }\n",
+        )
+        .unwrap();
+
+        scaffold(&root, 17).unwrap();
+        let read = |path: PathBuf| fs::read_to_string(path).unwrap();
+        assert!(read(root.join("Cargo.toml"))
+            .contains("    \"day16\",\n    \"day17\",\n    \"day18\",\n"));
+        assert!(read(root.join("aoc").join("Cargo.toml"))
+            .contains("day17 = { path = \"../day17\" }\nday18"));
+        assert!(read(root.join("aoc").join("src").join("days.rs"))
+            .contains("Day::of::<day16::Day16>(),\n    Day::of::<day17::Day17>(),\n];"));
+        assert!(read(root.join("web").join("Cargo.toml"))
+            .contains("day16 = { path = \"../day16\" }\nday17 = { path = \"../day17\" }\n"));
+        assert!(read(root.join("fuzz").join("Cargo.toml")).contains(
+            "bench = false },\n    { name = \"day17\", path = \"fuzz_targets/day17.rs\", \
+             test = false, doc = false, bench = false },\n]"
+        ));
+        assert!(read(root.join("fuzz").join("Cargo.toml"))
+            .contains("day16 = { path = \"../day16\" }\nday17 = { path = \"../day17\" }\n"));
+        assert!(
+            read(root.join("fuzz").join("fuzz_targets").join("day17.rs"))
+                .contains("Day::of::<day17::Day17>()")
+        );
+        assert!(read(root.join("day17").join("src").join("lib.rs"))
+            .contains("impl Solution for Day17 {\n    const DAY: u8 = 17;"));
+
+        assert!(scaffold(&root, 17).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,210 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::repl::Session;
+use clap::Args;
+use rustyline::{
+    completion::{Completer, Pair},
+    error::ReadlineError,
+    history::DefaultHistory,
+    Context, Editor, Helper, Highlighter, Hinter, Validator,
+};
+
+use crate::{
+    days,
+    input::{self, Source},
+};
+
+#[derive(Args)]
+pub struct ReplArgs {
+    /// Day number
+    day: u8,
+    /// Read the input from this file instead of `dayN/input.txt`
+    #[arg(short, long)]
+    input: Option<Source>,
+}
+
+/// The commands every session has on top of the day's, as name, usage and help.
+const BUILTINS: [(&str, &str, &str); 2] = [
+    ("help", "", "list the commands"),
+    ("quit", "", "end the session, as do `exit` and Ctrl-D"),
+];
+
+#[derive(Helper, Hinter, Highlighter, Validator)]
+struct Completions {
+    session: Session,
+}
+
+impl Completer for Completions {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let (start, words) = complete(&self.session, &line[..pos]);
+        let pairs = words
+            .into_iter()
+            .map(|word| Pair {
+                display: word.clone(),
+                replacement: word,
+            })
+            .collect();
+        Ok((start, pairs))
+    }
+}
+
+/// Where the word being typed at the end of `line` starts, and the words it could be completed to:
+/// command names for the first word and the command's own words after it.
+fn complete(session: &Session, line: &str) -> (usize, Vec<String>) {
+    let start = line.rfind(' ').map_or(0, |idx| idx + 1);
+    let (before, word) = line.split_at(start);
+    let mut words: Vec<String> = match before.split_whitespace().next() {
+        None => session
+            .commands()
+            .iter()
+            .map(|c| c.name)
+            .chain(BUILTINS.iter().map(|(name, _, _)| *name))
+            .map(str::to_string)
+            .collect(),
+        Some(command) => session.words(command),
+    };
+    words.retain(|w| w.starts_with(word));
+    words.sort();
+    words.dedup();
+    (start, words)
+}
+
+fn help(session: &Session) -> String {
+    let commands: Vec<(String, &str)> = session
+        .commands()
+        .iter()
+        .map(|c| (format!("{} {}", c.name, c.usage), c.help))
+        .chain(
+            BUILTINS
+                .iter()
+                .map(|(name, usage, help)| (format!("{name} {usage}"), *help)),
+        )
+        .map(|(usage, help)| (usage.trim_end().to_string(), help))
+        .collect();
+    let width = commands
+        .iter()
+        .map(|(usage, _)| usage.len())
+        .max()
+        .unwrap_or(0);
+    commands
+        .iter()
+        .map(|(usage, help)| format!("  {usage:width$}  {help}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The history shared by all sessions of a day, in the workspace's `target` directory.
+fn history_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("target")
+        .join(format!("repl-history-day{day}"))
+}
+
+pub fn repl(args: ReplArgs) -> Result<(), String> {
+    let day = days::find(args.day).ok_or_else(|| format!("day {} is not solved yet", args.day))?;
+    if matches!(args.input, Some(Source::Stdin)) {
+        return Err("the commands are read from stdin, so the input cannot be".to_string());
+    }
+    let input = input::load(day.day, args.input.as_ref())?;
+    let session = day
+        .repl(&input)
+        .map_err(|e| format!("could not explore day {}: {e}", day.day))?;
+    if session.commands().is_empty() {
+        return Err(format!(
+            "day {} has no commands to explore its input",
+            day.day
+        ));
+    }
+
+    let mut editor: Editor<Completions, DefaultHistory> =
+        Editor::new().map_err(|e| format!("could not start the prompt: {e}"))?;
+    let history = history_path(day.day);
+    // there is no history before the first session
+    let _ = editor.load_history(&history);
+    println!("Day {:>2}: input parsed, commands:", day.day);
+    println!("{}", help(&session));
+    editor.set_helper(Some(Completions { session }));
+
+    let prompt = format!("day{}> ", day.day);
+    loop {
+        let line = match editor.readline(&prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(e) => return Err(format!("could not read a command: {e}")),
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let _ = editor.add_history_entry(line);
+        let session = &editor.helper().unwrap().session;
+        match line {
+            "quit" | "exit" => break,
+            "help" => println!("{}", help(session)),
+            _ => match session.run(line) {
+                Ok(output) => println!("{output}"),
+                Err(e) => println!("error: {e}"),
+            },
+        }
+    }
+
+    if let Some(dir) = history.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+    }
+    editor
+        .save_history(&history)
+        .map_err(|e| format!("could not save the history to {}: {e}", history.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    #[test]
+    fn completes_commands_then_their_words() {
+        let session = days::find(8).unwrap().repl(EXAMPLE).unwrap();
+        assert_eq!(
+            complete(&session, ""),
+            (0, vec!["help".into(), "next".into(), "quit".into()])
+        );
+        assert_eq!(complete(&session, "ne"), (0, vec!["next".into()]));
+        assert_eq!(
+            complete(&session, "next L from B"),
+            (12, vec!["BBB".into()])
+        );
+        assert_eq!(
+            complete(&session, "next "),
+            (
+                5,
+                vec![
+                    "AAA".into(),
+                    "BBB".into(),
+                    "L".into(),
+                    "R".into(),
+                    "ZZZ".into(),
+                    "from".into()
+                ]
+            )
+        );
+        assert!(complete(&session, "quit ").1.is_empty());
+    }
+}
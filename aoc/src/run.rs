@@ -0,0 +1,287 @@
+use std::time::Duration;
+
+use aoc_common::{
+    parallel,
+    solution::{Day, Part},
+};
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+
+use crate::{
+    answers::{AnswerStore, Verdict},
+    input::{self, Source},
+    parts, Selection,
+};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Args)]
+pub struct RunArgs {
+    /// Day number or `all`
+    day: Selection,
+    /// Only solve this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the input from this file, or `-` for stdin, instead of `dayN/input.txt`
+    #[arg(short, long)]
+    input: Option<Source>,
+    /// Save answers that are not in the answer store yet
+    #[arg(long)]
+    record: bool,
+    /// How to print the results. The JSON and CSV formats include timings, for which the days
+    /// and parts are solved one after another instead of side by side
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+/// The result of solving one part, as printed in every format.
+struct Row {
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+    verdict: Option<Verdict>,
+    recorded: bool,
+    parse_time: Duration,
+    time: Duration,
+    input_hash: String,
+}
+
+impl Row {
+    fn status(&self) -> &'static str {
+        match &self.verdict {
+            None => "unsolved",
+            Some(Verdict::Correct) => "correct",
+            Some(Verdict::Wrong { .. }) => "wrong",
+            Some(Verdict::Rejected(_)) => "rejected",
+            Some(Verdict::New) if self.recorded => "recorded",
+            Some(Verdict::New) => "new",
+        }
+    }
+
+    fn expected(&self) -> Option<&str> {
+        match &self.verdict {
+            Some(Verdict::Wrong { expected }) => Some(expected),
+            _ => None,
+        }
+    }
+
+    fn text(&self) -> String {
+        let Some(answer) = &self.answer else {
+            return format!("Day {:>2} part {}: not solved", self.day, self.part);
+        };
+        let verdict = match &self.verdict {
+            Some(Verdict::Wrong { expected }) => format!("wrong, expected {expected}"),
+            Some(Verdict::Rejected(hint)) => format!("rejected before, {hint}"),
+            Some(Verdict::New) if self.recorded => "new, recorded".to_string(),
+            _ => self.status().to_string(),
+        };
+        format!(
+            "Day {:>2} part {}: {answer} ({verdict})",
+            self.day, self.part
+        )
+    }
+
+    fn json(&self) -> JsonRow<'_> {
+        JsonRow {
+            day: self.day,
+            part: self.part.number(),
+            answer: self.answer.as_deref(),
+            status: self.status(),
+            expected: self.expected(),
+            parse_ns: self.parse_time.as_nanos() as u64,
+            time_ns: self.time.as_nanos() as u64,
+            input_hash: &self.input_hash,
+        }
+    }
+
+    fn csv(&self) -> String {
+        [
+            self.day.to_string(),
+            self.part.to_string(),
+            csv_field(self.answer.as_deref().unwrap_or_default()),
+            self.status().to_string(),
+            csv_field(self.expected().unwrap_or_default()),
+            self.parse_time.as_nanos().to_string(),
+            self.time.as_nanos().to_string(),
+            self.input_hash.clone(),
+        ]
+        .join(",")
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRow<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a str>,
+    status: &'a str,
+    expected: Option<&'a str>,
+    parse_ns: u64,
+    time_ns: u64,
+    input_hash: &'a str,
+}
+
+const CSV_HEADER: &str = "day,part,answer,status,expected,parse_ns,time_ns,input_hash";
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn run(args: RunArgs, store: &mut AnswerStore) -> Result<(), String> {
+    if args.input.is_some() && matches!(args.day, Selection::All) {
+        return Err("`--input` can only be used when running a single day".to_string());
+    }
+    let parts = parts(args.part);
+    let mut rows = vec![];
+    if let Format::Csv = args.format {
+        println!("{CSV_HEADER}");
+    }
+    let solve = |day: &'static Day, concurrently: bool| {
+        let input = input::load(day.day, args.input.as_ref())?;
+        let answers = if concurrently {
+            day.run_concurrently(&input, &parts)
+        } else {
+            day.run(&input, &parts)
+        };
+        let answers = answers.map_err(|e| format!("could not solve day {}: {e}", day.day))?;
+        Ok::<_, String>((day, input::hash(&input), answers))
+    };
+    let days = args.day.days()?;
+    // the text format has no timings, so the days are solved in parallel, then reported in order
+    // as if solved one after another
+    let solved: Vec<_> = match args.format {
+        Format::Text => parallel::map(days, |day| solve(day, true)),
+        Format::Json | Format::Csv => days.into_iter().map(|day| solve(day, false)).collect(),
+    };
+    for result in solved {
+        let (day, input_hash, answers) = result?;
+        for answer in answers.parts {
+            let mut verdict = None;
+            let mut recorded = false;
+            if let Some(a) = &answer.answer {
+                verdict = Some(store.check(day.day, &input_hash, answer.part, a));
+                if args.record && verdict == Some(Verdict::New) {
+                    store.record(day.day, &input_hash, answer.part, a);
+                    recorded = true;
+                }
+            }
+            let row = Row {
+                day: day.day,
+                part: answer.part,
+                answer: answer.answer,
+                verdict,
+                recorded,
+                parse_time: answers.parse_time,
+                time: answer.time,
+                input_hash: input_hash.clone(),
+            };
+            match args.format {
+                Format::Text => println!("{}", row.text()),
+                Format::Csv => println!("{}", row.csv()),
+                Format::Json => {}
+            }
+            rows.push(row);
+        }
+    }
+    if let Format::Json = args.format {
+        let json: Vec<_> = rows.iter().map(Row::json).collect();
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    }
+    if args.record {
+        store.save()?;
+    }
+    let wrong_answers = rows
+        .iter()
+        .filter(|row| {
+            matches!(
+                row.verdict,
+                Some(Verdict::Wrong { .. } | Verdict::Rejected(_))
+            )
+        })
+        .count();
+    match wrong_answers {
+        0 => Ok(()),
+        1 => Err("1 answer does not match the answer store".to_string()),
+        n => Err(format!("{n} answers do not match the answer store")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::solution::Answers;
+
+    use super::*;
+
+    #[test]
+    fn csv_fields_are_quoted() {
+        assert_eq!(csv_field("46"), "46");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn concurrent_answers_match() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200\n";
+        let answers = |answers: Answers| {
+            answers
+                .parts
+                .into_iter()
+                .map(|part| (part.part, part.answer))
+                .collect::<Vec<_>>()
+        };
+        let day = crate::days::find(6).unwrap();
+        let parts = [Part::One, Part::Two];
+        assert_eq!(
+            answers(day.run(input, &parts).unwrap()),
+            answers(day.run_concurrently(input, &parts).unwrap())
+        );
+    }
+
+    #[test]
+    fn unsolvable_parts_are_errors() {
+        let day = crate::days::find(8).unwrap();
+        let input = "RL\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        assert_eq!(
+            day.run_concurrently(input, &[Part::One, Part::Two])
+                .unwrap_err()
+                .to_string(),
+            "part 1: `AAA` never reaches `ZZZ`"
+        );
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn overflows_are_errors() {
+        let solve = |day, input, part| {
+            crate::days::find(day)
+                .unwrap()
+                .run(input, &[part])
+                .unwrap_err()
+                .to_string()
+        };
+        // the concatenated race is too long to hold half of in either width
+        assert_eq!(
+            solve(6, "Time: 9999999999 9999999999\nDistance: 1 1\n", Part::Two),
+            "part 2: attempt to multiply with overflow"
+        );
+        // in either width, as another crate of the build may turn on `wide`
+        let destination = aoc_common::number::Uint::MAX;
+        assert_eq!(
+            solve(
+                5,
+                &format!("seeds: 5 1\n\nseed-to-soil map:\n{destination} 0 10\n"),
+                Part::One
+            ),
+            "part 1: attempt to add with overflow"
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use clap::Args;
+
+use crate::{
+    answers::{AnswerStore, Verdict},
+    client::{Client, ClientArgs, Response},
+    days, input, parts,
+};
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// Day number
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Part to submit the answer of
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Answer to submit, solved from `dayN/input.txt` by default
+    answer: Option<String>,
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+pub fn submit(args: SubmitArgs, store: &mut AnswerStore) -> Result<(), String> {
+    let day = args.day;
+    let part = parts(Some(args.part))[0];
+    let input = input::load(day, None)?;
+    let input_hash = input::hash(&input);
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => days::find(day)
+            .ok_or_else(|| format!("day {day} is not solved yet"))?
+            .run(&input, &[part])
+            .map_err(|e| format!("could not solve day {day}: {e}"))?
+            .parts
+            .remove(0)
+            .answer
+            .ok_or_else(|| format!("part {part} of day {day} is not solved yet"))?,
+    };
+
+    match store.check(day, &input_hash, part, &answer) {
+        Verdict::Correct => {
+            println!("Day {day:>2} part {part}: {answer} (already correct)");
+            return Ok(());
+        }
+        Verdict::Wrong { expected } => {
+            return Err(format!(
+                "{answer} is wrong, the answer store has {expected}"
+            ));
+        }
+        Verdict::Rejected(hint) => {
+            return Err(format!("{answer} was rejected before, it is {hint}"));
+        }
+        Verdict::New => {}
+    }
+
+    match Client::new(args.client)?.submit(day, part, &answer)? {
+        Response::Correct => {
+            store.record(day, &input_hash, part, &answer);
+            store.save()?;
+            println!("Day {day:>2} part {part}: {answer} (correct, recorded)");
+            Ok(())
+        }
+        Response::Rejected(hint) => {
+            store.reject(day, &input_hash, part, &answer, hint);
+            store.save()?;
+            Err(format!("{answer} is {hint}"))
+        }
+        Response::Wait(wait) => Err(format!(
+            "answered too recently, wait {}s before submitting again",
+            wait.as_secs()
+        )),
+        Response::WrongLevel => Err(format!(
+            "part {part} of day {day} is already solved or not unlocked yet"
+        )),
+    }
+}
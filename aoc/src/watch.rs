@@ -0,0 +1,200 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use clap::Args;
+use serde::Deserialize;
+
+use crate::{answers::AnswerStore, days, input};
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Day number
+    day: u8,
+    /// How often to look for changes, in milliseconds
+    #[arg(long, default_value_t = 500)]
+    interval: u64,
+    /// Only solve the day, without running its tests
+    #[arg(long)]
+    no_tests: bool,
+}
+
+/// One part's result, as `run --format json` prints it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct Outcome {
+    part: u8,
+    answer: Option<String>,
+    status: String,
+    expected: Option<String>,
+}
+
+impl Outcome {
+    /// The outcome as a line, pointing out how it changed since the `previous` run.
+    fn describe(&self, day: u8, previous: Option<&Outcome>) -> String {
+        let Some(answer) = &self.answer else {
+            return format!("Day {day:>2} part {}: not solved", self.part);
+        };
+        let mut line = format!(
+            "Day {day:>2} part {}: {answer} ({})",
+            self.part, self.status
+        );
+        match previous.and_then(|p| p.answer.as_ref()) {
+            Some(before) if before != answer => line += &format!(", was {before}"),
+            Some(_) => line += ", unchanged",
+            None => {}
+        }
+        if self.status == "wrong" {
+            let expected = self.expected.as_deref().unwrap_or_default();
+            line += &format!("\n  REGRESSION: the answer store has {expected}");
+        } else if self.status == "rejected" {
+            line += "\n  REGRESSION: the puzzle server rejected this answer before";
+        }
+        line
+    }
+}
+
+pub fn watch(args: WatchArgs, store: &AnswerStore) -> Result<(), String> {
+    let day = days::find(args.day).ok_or_else(|| format!("day {} is not solved yet", args.day))?;
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let crate_dir = root.join(format!("day{}", day.day));
+    let watched = [
+        crate_dir.join("src"),
+        crate_dir.join("Cargo.toml"),
+        input::default_path(day.day),
+    ];
+    println!(
+        "Watching {} and {} for changes, press Ctrl-C to stop",
+        crate_dir.display(),
+        input::default_path(day.day).display()
+    );
+
+    let mut seen = None;
+    let mut previous: Vec<Outcome> = vec![];
+    loop {
+        let changes = modification_times(&watched);
+        if seen.as_ref() != Some(&changes) {
+            match seen.replace(changes) {
+                Some(_) => println!("\n--- day {} changed, rebuilding", day.day),
+                None => println!("\n--- building day {}", day.day),
+            }
+            if let Some(outcomes) = solve(&root, day.day, store.path()) {
+                for outcome in &outcomes {
+                    let before = previous.iter().find(|p| p.part == outcome.part);
+                    println!("{}", outcome.describe(day.day, before));
+                }
+                previous = outcomes;
+            }
+            if !args.no_tests {
+                test(&root, day.day);
+            }
+        }
+        thread::sleep(Duration::from_millis(args.interval));
+    }
+}
+
+/// When each file in `paths` or in directories below them was last changed.
+fn modification_times(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut times = BTreeMap::new();
+    let mut pending = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.filter_map(|entry| Some(entry.ok()?.path())));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            times.insert(path, modified);
+        }
+    }
+    times
+}
+
+/// Runs `cargo` in the workspace, which rebuilds the runner with the changed day first.
+fn cargo(root: &Path, args: &[&str]) -> Result<Output, String> {
+    Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(args)
+        .current_dir(root)
+        .output()
+        .map_err(|e| format!("could not run cargo: {e}"))
+}
+
+fn solve(root: &Path, day: u8, answers: &Path) -> Option<Vec<Outcome>> {
+    let day = day.to_string();
+    let answers = answers.to_string_lossy();
+    let args = [
+        "run",
+        "-q",
+        "-p",
+        "aoc",
+        "--",
+        "--answers",
+        &answers,
+        "run",
+        &day,
+        "--format",
+        "json",
+    ];
+    let output = match cargo(root, &args) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return None;
+        }
+    };
+    // wrong answers make the run fail as well, but still print all results
+    match serde_json::from_slice(&output.stdout) {
+        Ok(outcomes) => Some(outcomes),
+        Err(_) => {
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            None
+        }
+    }
+}
+
+fn test(root: &Path, day: u8) {
+    let package = format!("day{day}");
+    match cargo(root, &["test", "-q", "-p", &package]) {
+        Ok(output) if output.status.success() => println!("Day {day:>2} tests: passed"),
+        Ok(output) => {
+            println!("Day {day:>2} tests: FAILED");
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+        Err(e) => eprintln!("error: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(answer: &str, status: &str, expected: Option<&str>) -> Outcome {
+        Outcome {
+            part: 1,
+            answer: Some(answer.to_string()),
+            status: status.to_string(),
+            expected: expected.map(String::from),
+        }
+    }
+
+    #[test]
+    fn describe_changes_and_regressions() {
+        let before = outcome("136", "correct", None);
+        assert_eq!(before.describe(14, None), "Day 14 part 1: 136 (correct)");
+        assert_eq!(
+            before.describe(14, Some(&before)),
+            "Day 14 part 1: 136 (correct), unchanged"
+        );
+        assert_eq!(
+            outcome("140", "wrong", Some("136")).describe(14, Some(&before)),
+            "Day 14 part 1: 140 (wrong), was 136\n  REGRESSION: the answer store has 136"
+        );
+    }
+}
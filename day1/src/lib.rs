@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    number::{add, mul, sum, Uint},
+    parse::{lines, ParseError},
+    solution::Solution,
+};
+
+/// Spells out the digits written as words, keeping both digits of overlapping words like `eightwo`.
+fn spell_out(line: &str) -> String {
+    line.replace("nineight", "98")
+        .replace("eighthree", "83")
+        .replace("eightwo", "82")
+        .replace("twone", "21")
+        .replace("oneight", "18")
+        .replace("threeight", "38")
+        .replace("fiveight", "58")
+        .replace("sevenine", "79")
+        .replace("one", "1")
+        .replace("two", "2")
+        .replace("three", "3")
+        .replace("four", "4")
+        .replace("five", "5")
+        .replace("six", "6")
+        .replace("seven", "7")
+        .replace("eight", "8")
+        .replace("nine", "9")
+}
+
+/// The digits of every line, with `spelled` also those written as words.
+fn digits(input_str: &str, spelled: bool) -> Result<Vec<Vec<u32>>, ParseError> {
+    lines(input_str)
+        .map(|line| {
+            let text = if spelled {
+                spell_out(line.text)
+            } else {
+                line.text.to_string()
+            };
+            let digits: Vec<u32> = text.chars().filter_map(|c| c.to_digit(10)).collect();
+            if digits.is_empty() {
+                return Err(line.error(line.text, "a line with a digit"));
+            }
+            Ok(digits)
+        })
+        .collect()
+}
+
+/// Sums the numbers made of the first and the last digit of every line.
+fn calibration_sum(lines: &[Vec<u32>]) -> Uint {
+    sum(lines.iter().map(|digits| {
+        add(
+            mul(Uint::from(digits[0]), 10),
+            Uint::from(digits[digits.len() - 1]),
+        )
+    }))
+}
+
+fn part1(digits: &[Vec<u32>]) -> Uint {
+    calibration_sum(digits)
+}
+
+fn part2(spelled_digits: &[Vec<u32>]) -> Uint {
+    calibration_sum(spelled_digits)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    /// The digits of every line, without and with the spelled out ones.
+    type Input = (Vec<Vec<u32>>, Vec<Vec<u32>>);
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        Ok((digits(input_str, false)?, digits(input_str, true)?))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(&input.0)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(&input.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+    const EXAMPLE_2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day1::parse(EXAMPLE).unwrap().0), 142);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&digits(EXAMPLE_2, true).unwrap()), 281);
+    }
+
+    #[test]
+    fn lines_without_a_digit_are_errors() {
+        assert_eq!(
+            Day1::parse("1abc2\ntreb7uchet\nabc\n")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 1: expected a line with a digit, found `abc`"
+        );
+        // part 1 needs a digit even where part 2 finds one spelled out
+        assert_eq!(
+            Day1::parse("1two\ntwo\n").unwrap_err().to_string(),
+            "line 2, column 1: expected a line with a digit, found `two`"
+        );
+    }
+}
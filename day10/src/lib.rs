@@ -0,0 +1,282 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_common::{
+    animation::Color,
+    drawing::{Drawing, Rgb},
+    generate::{random_loop, Options, Rng},
+    geometry::Direction::{self, *},
+    grid::{FromChar, Grid},
+    parse::{Found, ParseError},
+    solution::Solution,
+    Position,
+};
+
+#[derive(Debug, Clone)]
+pub struct Pipe(Vec<Direction>);
+
+impl FromChar for Pipe {
+    const EXPECTED: &'static str = "a pipe or `.`";
+
+    fn from_char(c: char) -> Option<Self> {
+        Some(Self(match c {
+            '|' => vec![North, South],
+            '-' => vec![West, East],
+            'L' => vec![North, East],
+            'J' => vec![North, West],
+            '7' => vec![South, West],
+            'F' => vec![South, East],
+            'S' => vec![North, East, South, West],
+            '.' => vec![],
+            _ => return None,
+        }))
+    }
+}
+
+fn parse(input_str: &str) -> Result<(Grid<Pipe>, Position), ParseError> {
+    let mut pipe_map: Grid<Pipe> = input_str.parse()?;
+    let start_position = pipe_map
+        .position(|pipe| pipe.0.len() == 4)
+        .ok_or_else(|| ParseError::end_of_input(input_str, "a start tile `S`"))?;
+    // the start pipe connects to all neighbours that connect back to it
+    let start_pipe: Vec<Direction> = [North, South, West, East]
+        .into_iter()
+        .filter(|dir| {
+            pipe_map
+                .step(start_position, *dir)
+                .is_some_and(|pos| pipe_map[pos].0.contains(&dir.turn_back()))
+        })
+        .collect();
+    if start_pipe.len() != 2 {
+        return Err(ParseError {
+            line: start_position.0 + 1,
+            column: start_position.1 + 1,
+            expected: "a start tile connected to exactly two pipes".to_string(),
+            found: Found::Token("S".to_string()),
+        });
+    }
+    // replace start position with actual pipe
+    pipe_map[start_position] = Pipe(start_pipe);
+    Ok((pipe_map, start_position))
+}
+
+fn find_next_pos(pipe_map: &Grid<Pipe>, prev_pos: Position, current_pos: Position) -> Position {
+    let from_direction = Direction::from_positions(prev_pos, current_pos);
+    pipe_map[current_pos]
+        .0
+        .iter()
+        .find(|dir| dir != &&from_direction.turn_back())
+        .unwrap()
+        .step(current_pos)
+}
+
+fn find_loop(pipe_map: &Grid<Pipe>, start_pos: Position) -> Vec<Position> {
+    let mut loop_positions: Vec<Position> = vec![start_pos];
+    loop_positions.push(pipe_map[start_pos].0.first().unwrap().step(start_pos));
+    while loop_positions.last().unwrap() != &start_pos {
+        loop_positions.push(find_next_pos(
+            pipe_map,
+            *loop_positions.iter().rev().nth(1).unwrap(),
+            *loop_positions.last().unwrap(),
+        ));
+    }
+    loop_positions
+}
+
+fn is_inside_loop(
+    pipe_map: &Grid<Pipe>,
+    loop_positions: &HashSet<Position>,
+    position: Position,
+) -> bool {
+    let mut crossings = 0;
+    let mut current_pos = position;
+    // we go up from the position and check how often we cross the loop
+    while current_pos.0 != 0 {
+        current_pos = (current_pos.0 - 1, current_pos.1);
+        if !loop_positions.contains(&current_pos) {
+            continue;
+        }
+        if pipe_map[current_pos].0.contains(&East) {
+            crossings += 1;
+        }
+    }
+    crossings % 2 != 0
+}
+
+fn part1(pipe_map: &Grid<Pipe>, start_pos: Position) -> usize {
+    (find_loop(pipe_map, start_pos).len() - 1) / 2
+}
+
+fn enclosed_tiles(pipe_map: &Grid<Pipe>, loop_positions: &HashSet<Position>) -> Vec<Position> {
+    let mut enclosed_tiles = vec![];
+    for pos1 in loop_positions.iter().map(|(x, _)| x).min().unwrap() + 1
+        ..*loop_positions.iter().map(|(x, _)| x).max().unwrap()
+    {
+        for pos2 in loop_positions.iter().map(|(_, y)| y).min().unwrap() + 1
+            ..*loop_positions.iter().map(|(_, y)| y).max().unwrap()
+        {
+            if loop_positions.contains(&(pos1, pos2)) {
+                continue;
+            }
+            if is_inside_loop(pipe_map, loop_positions, (pos1, pos2)) {
+                enclosed_tiles.push((pos1, pos2));
+            }
+        }
+    }
+    enclosed_tiles
+}
+
+fn part2(pipe_map: &Grid<Pipe>, start_pos: Position) -> usize {
+    let loop_positions: HashSet<Position> = HashSet::from_iter(find_loop(pipe_map, start_pos));
+    enclosed_tiles(pipe_map, &loop_positions).len()
+}
+
+/// The loop in yellow and the tiles it encloses in green.
+fn draw(pipe_map: &Grid<Pipe>, start_pos: Position) -> Grid<Rgb> {
+    let loop_positions: HashSet<Position> = HashSet::from_iter(find_loop(pipe_map, start_pos));
+    let mut picture = Grid::filled(pipe_map.width(), pipe_map.height(), Rgb::BACKGROUND);
+    for pos in &loop_positions {
+        picture[*pos] = Color::Yellow.into();
+    }
+    for pos in enclosed_tiles(pipe_map, &loop_positions) {
+        picture[pos] = Color::Green.into();
+    }
+    picture
+}
+
+/// A `size` by `size` maze with a single loop spreading over a `density` share of it, the other
+/// tiles hold junk pipes with probability `density`.
+fn generate(rng: &mut Rng, options: &Options) -> String {
+    const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+    let size = options.size.max(3);
+    let path = random_loop(rng, size, options.density);
+    let mut tiles = Grid::from_fn(size, size, |_| {
+        if rng.chance(options.density) {
+            *rng.choose(&PIPES)
+        } else {
+            '.'
+        }
+    });
+    let start = path[rng.below(path.len())];
+    // junk must not connect to the start tile, or it could not tell which pipes it joins
+    for direction in Direction::ALL {
+        if let Some(pos) = tiles.step(start, direction) {
+            tiles[pos] = '.';
+        }
+    }
+    for (idx, &pos) in path.iter().enumerate() {
+        let prev = path[(idx + path.len() - 1) % path.len()];
+        let next = path[(idx + 1) % path.len()];
+        let mut connections = [
+            Direction::from_positions(pos, prev),
+            Direction::from_positions(pos, next),
+        ];
+        connections.sort();
+        tiles[pos] = match connections {
+            [North, South] => '|',
+            [East, West] => '-',
+            [North, East] => 'L',
+            [North, West] => 'J',
+            [South, West] => '7',
+            [East, South] => 'F',
+            _ => unreachable!("the loop never turns back"),
+        };
+    }
+    tiles[start] = 'S';
+    tiles.to_string() + "\n"
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = (Grid<Pipe>, Position);
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(&input.0, input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(&input.0, input.1))
+    }
+
+    fn draw(input: &Self::Input) -> Option<Drawing> {
+        Some(Drawing::Grid(draw(&input.0, input.1)))
+    }
+
+    fn generate(rng: &mut Rng, options: &Options) -> Option<String> {
+        Some(generate(rng, options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+    const EXAMPLE_2: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+    const EXAMPLE_3: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+    #[test]
+    fn part1_example() {
+        let (pipe_map, start_pos) = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&pipe_map, start_pos), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        let (pipe_map, start_pos) = Day10::parse(EXAMPLE_2).unwrap();
+        assert_eq!(part2(&pipe_map, start_pos), 4);
+        let (pipe_map, start_pos) = Day10::parse(EXAMPLE_3).unwrap();
+        assert_eq!(part2(&pipe_map, start_pos), 10);
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let (pipe_map, start_pos) = Day10::parse(&input).unwrap();
+            assert!(part2(&pipe_map, start_pos) > 0);
+        }
+        // a single ring of 8 pipes around one tile
+        let options = Options {
+            size: 3,
+            density: 0.0,
+        };
+        let (pipe_map, start_pos) = Day10::parse(&generate(&mut Rng::new(0), &options)).unwrap();
+        assert_eq!(part1(&pipe_map, start_pos), 4);
+        assert_eq!(part2(&pipe_map, start_pos), 1);
+    }
+}
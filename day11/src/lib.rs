@@ -0,0 +1,184 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    grid::Grid,
+    number::{add, mul, sub, Uint},
+    parallel,
+    parse::ParseError,
+    solution::Solution,
+    Position,
+};
+
+fn parse_input(input_str: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input_str, "`#` or `.`", |c| match c {
+        '#' => Some(1),
+        '.' => Some(0),
+        _ => None,
+    })
+}
+
+fn find_empty_spaces(map: &Grid<u8>) -> (Vec<usize>, Vec<usize>) {
+    (
+        (0..map.height())
+            .filter(|l_idx| map.row(*l_idx).iter().all(|s| s == &0))
+            .collect(),
+        (0..map.width())
+            .filter(|col_idx| map.column(*col_idx).all(|s| s == &0))
+            .collect(),
+    )
+}
+
+fn galaxy_positions(map: &Grid<u8>) -> Vec<Position> {
+    map.iter()
+        .filter(|(_, s)| s == &&1)
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn expanded_galaxy_positions(map: &Grid<u8>, expansion: usize) -> Vec<Position> {
+    let (empty_rows, empty_columns) = find_empty_spaces(map);
+    let mut new_positions = vec![];
+    for position in galaxy_positions(map) {
+        new_positions.push((
+            add(
+                position.0,
+                mul(
+                    empty_rows
+                        .iter()
+                        .filter(|r_idx| r_idx < &&position.0)
+                        .count(),
+                    expansion,
+                ),
+            ),
+            add(
+                position.1,
+                mul(
+                    empty_columns
+                        .iter()
+                        .filter(|c_idx| c_idx < &&position.1)
+                        .count(),
+                    expansion,
+                ),
+            ),
+        ));
+    }
+    new_positions
+}
+
+/// Sums the distances between all pairs of `coordinates`. Once sorted, every coordinate is the
+/// larger one of the pairs with all coordinates before it.
+fn sum_of_differences(mut coordinates: Vec<usize>) -> Uint {
+    coordinates.sort_unstable();
+    let mut total = 0;
+    let mut smaller_sum = 0;
+    for (idx, &coordinate) in coordinates.iter().enumerate() {
+        // the sums overflow a `usize` on 32 bit targets
+        let coordinate = coordinate as Uint;
+        total = add(total, sub(mul(coordinate, idx as Uint), smaller_sum));
+        smaller_sum = add(smaller_sum, coordinate);
+    }
+    total
+}
+
+fn total_distance(map: &Grid<u8>, expansion_factor: usize) -> Uint {
+    // every empty row and column is replaced by `expansion_factor` of them
+    let positions = expanded_galaxy_positions(map, expansion_factor - 1);
+    let (rows, columns) = parallel::solver_join(
+        || sum_of_differences(positions.iter().map(|pos| pos.0).collect()),
+        || sum_of_differences(positions.iter().map(|pos| pos.1).collect()),
+    );
+    add(rows, columns)
+}
+
+fn part1(map: &Grid<u8>) -> Uint {
+    total_distance(map, 2)
+}
+
+fn part2(map: &Grid<u8>) -> Uint {
+    total_distance(map, 1_000_000)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Grid<u8>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::check_property;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day11::parse(EXAMPLE).unwrap()), 374);
+    }
+
+    #[test]
+    fn larger_expansion_example() {
+        let map = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(total_distance(&map, 10), 1030);
+        assert_eq!(total_distance(&map, 100), 8410);
+    }
+
+    /// Adds up the distances of every pair of galaxies, counting the empty rows and columns
+    /// between them `expansion_factor` times.
+    fn pairwise_distance(map: &Grid<u8>, expansion_factor: usize) -> Uint {
+        let galaxies = galaxy_positions(map);
+        let (empty_rows, empty_columns) = find_empty_spaces(map);
+        let distance = |a: usize, b: usize, empty: &[usize]| {
+            let between = empty.iter().filter(|e| (a.min(b)..a.max(b)).contains(e));
+            a.abs_diff(b) + between.count() * (expansion_factor - 1)
+        };
+        let mut total = 0;
+        for (idx, a) in galaxies.iter().enumerate() {
+            for b in &galaxies[idx + 1..] {
+                total +=
+                    (distance(a.0, b.0, &empty_rows) + distance(a.1, b.1, &empty_columns)) as Uint;
+            }
+        }
+        total
+    }
+
+    #[test]
+    fn matches_pairwise_distances() {
+        check_property(100, |rng| {
+            let density = rng.range(0..=10) as f64 / 20.0;
+            let (width, height) = (rng.below(15) + 1, rng.below(15) + 1);
+            let map = Grid::from_fn(width, height, |_| rng.chance(density) as u8);
+            for expansion_factor in [1, 2, 10, 1_000_000] {
+                assert_eq!(
+                    total_distance(&map, expansion_factor),
+                    pairwise_distance(&map, expansion_factor)
+                );
+            }
+        });
+    }
+}
@@ -0,0 +1,377 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    generate::{Options, Rng},
+    memo::{tabulate, Memo, Stats},
+    number::{add, Uint},
+    parallel,
+    parse::{lines, ParseError},
+    repl::Command,
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Tile {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+type Row = (Vec<Tile>, Vec<u8>);
+
+/// How far into a row's tiles and groups the springs left to fill in start.
+type Offsets = (usize, usize);
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operational => write!(f, "."),
+            Damaged => write!(f, "#"),
+            Unknown => write!(f, "?"),
+        }
+    }
+}
+use Tile::*;
+
+fn parse(input_str: &str) -> Result<Vec<Row>, ParseError> {
+    lines(input_str)
+        .map(|line| {
+            let (tiles, groups) = line.split_once(line.text, " ")?;
+            Ok((
+                tiles
+                    .char_indices()
+                    .map(|(idx, c)| match c {
+                        '.' => Ok(Operational),
+                        '#' => Ok(Damaged),
+                        '?' => Ok(Unknown),
+                        _ => Err(line.error(&tiles[idx..idx + c.len_utf8()], "`.`, `#` or `?`")),
+                    })
+                    .collect::<Result<_, _>>()?,
+                groups
+                    .split(',')
+                    .map(|d| line.parse(d, "a group size"))
+                    .collect::<Result<_, _>>()?,
+            ))
+        })
+        .collect()
+}
+
+/// The number of ways to fill in the unknown springs of `tiles[tile..]` so that the damaged ones
+/// form `groups[group..]`, looking up the rest of the row after the next tile or group in `ways`.
+fn combinations(
+    tiles: &[Tile],
+    groups: &[u8],
+    ways: &mut dyn FnMut(Offsets) -> Uint,
+    (tile, group): Offsets,
+) -> Uint {
+    if group == groups.len() {
+        return !tiles[tile..].contains(&Damaged) as Uint;
+    }
+    if tile == tiles.len() {
+        return 0;
+    }
+    let operational = |ways: &mut dyn FnMut(Offsets) -> Uint| ways((tile + 1, group));
+    let damaged = |ways: &mut dyn FnMut(Offsets) -> Uint| {
+        let size = groups[group] as usize;
+        let rest = &tiles[tile..];
+        if rest
+            .iter()
+            .take(size)
+            .filter(|t| t != &&Operational)
+            .count()
+            != size
+        {
+            return 0;
+        }
+        if rest.len() == size {
+            return (group + 1 == groups.len()) as Uint;
+        }
+        if matches!(rest[size], Unknown | Operational) {
+            return ways((tile + size + 1, group + 1));
+        }
+        0
+    };
+    match tiles[tile] {
+        Operational => operational(ways),
+        Damaged => damaged(ways),
+        Unknown => add(operational(ways), damaged(ways)),
+    }
+}
+
+/// How [`combinations`] is computed: recursing from the start of the row and caching the offsets
+/// it comes across, or filling in every offset from the end of the row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Strategy {
+    Memoized,
+    Tabulated,
+}
+
+impl Strategy {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "memoized" => Some(Self::Memoized),
+            "tabulated" => Some(Self::Tabulated),
+            _ => None,
+        }
+    }
+}
+
+fn calc_combinations(tiles: &[Tile], groups: &[u8], strategy: Strategy) -> (Uint, Stats) {
+    let f =
+        |ways: &mut dyn FnMut(Offsets) -> Uint, offsets| combinations(tiles, groups, ways, offsets);
+    match strategy {
+        Strategy::Memoized => {
+            let mut memo = Memo::new();
+            (memo.get((0, 0), &f), memo.stats())
+        }
+        Strategy::Tabulated => {
+            // every offset only depends on larger ones
+            let offsets = (0..=tiles.len())
+                .rev()
+                .flat_map(|tile| (0..=groups.len()).rev().map(move |group| (tile, group)));
+            let table = tabulate(offsets, f);
+            (table.get(&(0, 0)).copied().unwrap(), table.stats())
+        }
+    }
+}
+
+fn unfold((tiles, groups): &Row) -> Row {
+    let mut new_tiles = tiles.clone();
+    new_tiles.push(Unknown);
+    new_tiles = new_tiles.repeat(5);
+    new_tiles.pop();
+    (new_tiles, groups.repeat(5))
+}
+
+/// The arrangements of all rows, unfolded for part 2, together with the lookups it took.
+fn arrangements(rows: &[Row], unfolded: bool, strategy: Strategy) -> (Uint, Stats) {
+    parallel::solver_map(rows.iter().collect(), |row| {
+        let unfolded_row;
+        let (tiles, groups) = if unfolded {
+            unfolded_row = unfold(row);
+            &unfolded_row
+        } else {
+            row
+        };
+        calc_combinations(tiles, groups, strategy)
+    })
+    .into_iter()
+    .fold(
+        (0, Stats::default()),
+        |(total, mut stats), (count, row_stats)| {
+            stats += row_stats;
+            (add(total, count), stats)
+        },
+    )
+}
+
+fn part1(parsed_input: &[Row]) -> Uint {
+    arrangements(parsed_input, false, Strategy::Memoized).0
+}
+
+fn part2(parsed_input: &[Row]) -> Uint {
+    arrangements(parsed_input, true, Strategy::Memoized).0
+}
+
+/// The `stats <1|2> <memoized|tabulated>` command of `aoc repl`.
+fn stats_command(rows: &[Row], args: &str) -> Result<String, String> {
+    let usage = "expected `<1|2> <memoized|tabulated>`";
+    let (part, strategy) = args.split_once(' ').ok_or(usage)?;
+    let unfolded = match part {
+        "1" => false,
+        "2" => true,
+        _ => return Err(usage.to_string()),
+    };
+    let strategy = Strategy::parse(strategy.trim()).ok_or(usage)?;
+    let (total, stats) = arrangements(rows, unfolded, strategy);
+    Ok(format!("{total} arrangements, {stats}"))
+}
+
+/// `size` rows of up to 20 springs, a `density` share of which are unknown.
+fn generate(rng: &mut Rng, options: &Options) -> String {
+    let mut input = String::new();
+    for _ in 0..options.size {
+        let mut springs: Vec<Tile> = (0..rng.range(1..=20))
+            .map(|_| *rng.choose(&[Operational, Damaged]))
+            .collect();
+        // every row has at least one group
+        if !springs.contains(&Damaged) {
+            let idx = rng.below(springs.len());
+            springs[idx] = Damaged;
+        }
+        let groups: Vec<String> = springs
+            .split(|s| *s == Operational)
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        for spring in &mut springs {
+            if rng.chance(options.density) {
+                *spring = Unknown;
+            }
+        }
+        let springs: String = springs.iter().map(Tile::to_string).collect();
+        input += &format!("{springs} {}\n", groups.join(","));
+    }
+    input
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Vec<Row>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(input))
+    }
+
+    fn generate(rng: &mut Rng, options: &Options) -> Option<String> {
+        Some(generate(rng, options))
+    }
+
+    fn commands() -> Vec<Command<Self::Input>> {
+        vec![Command {
+            name: "stats",
+            usage: "<1|2> <memoized|tabulated>",
+            help: "the arrangements of a part and the cache lookups counting them took",
+            run: |rows, args| stats_command(rows, args),
+            words: |_| {
+                ["1", "2", "memoized", "tabulated"]
+                    .map(String::from)
+                    .to_vec()
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::check_property;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day12::parse(EXAMPLE).unwrap()), 21);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&Day12::parse(EXAMPLE).unwrap()), 525152);
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let rows = Day12::parse(&input).unwrap();
+            // the springs the row was generated from are always one arrangement
+            assert!(rows.iter().all(|(tiles, groups)| {
+                calc_combinations(tiles, groups, Strategy::Memoized).0 > 0
+            }));
+        }
+    }
+
+    /// Tries every way to fill in the unknown springs.
+    fn brute_force_combinations(tiles: &[Tile], groups: &[u8]) -> Uint {
+        let unknown: Vec<usize> = (0..tiles.len()).filter(|i| tiles[*i] == Unknown).collect();
+        (0..1u32 << unknown.len())
+            .filter(|filled| {
+                let mut tiles = tiles.to_vec();
+                for (bit, idx) in unknown.iter().enumerate() {
+                    tiles[*idx] = if filled >> bit & 1 == 1 {
+                        Damaged
+                    } else {
+                        Operational
+                    };
+                }
+                let found: Vec<u8> = tiles
+                    .split(|t| *t == Operational)
+                    .filter(|group| !group.is_empty())
+                    .map(|group| group.len() as u8)
+                    .collect();
+                found == groups
+            })
+            .count() as Uint
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        check_property(50, |rng| {
+            let options = Options {
+                size: 10,
+                density: rng.range(0..=10) as f64 / 10.0,
+            };
+            let rows = Day12::parse(&generate(rng, &options)).unwrap();
+            for (tiles, groups) in rows.iter().filter(|(t, _)| t.len() <= 14) {
+                assert_eq!(
+                    calc_combinations(tiles, groups, Strategy::Memoized).0,
+                    brute_force_combinations(tiles, groups),
+                    "{}",
+                    tiles.iter().map(Tile::to_string).collect::<String>()
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn tabulating_matches_recursing() {
+        check_property(20, |rng| {
+            let rows = Day12::parse(&generate(rng, &Options::default())).unwrap();
+            for (tiles, groups) in &rows {
+                assert_eq!(
+                    calc_combinations(tiles, groups, Strategy::Tabulated).0,
+                    calc_combinations(tiles, groups, Strategy::Memoized).0
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn stats_add_up_over_rows() {
+        let rows = Day12::parse(EXAMPLE).unwrap();
+        let (total, stats) = arrangements(&rows, true, Strategy::Memoized);
+        assert_eq!(total, 525152);
+        let row_stats: Vec<Stats> = rows
+            .iter()
+            .map(|row| {
+                let (tiles, groups) = unfold(row);
+                calc_combinations(&tiles, &groups, Strategy::Memoized).1
+            })
+            .collect();
+        assert_eq!(stats.hits, row_stats.iter().map(|s| s.hits).sum::<u64>());
+        assert_eq!(
+            stats.misses,
+            row_stats.iter().map(|s| s.misses).sum::<u64>()
+        );
+        assert!(stats.hits > 0);
+
+        // tabulating computes every offset of every row
+        let (total, stats) = arrangements(&rows, false, Strategy::Tabulated);
+        assert_eq!(total, 21);
+        let offsets = rows.iter().map(|(t, g)| (t.len() + 1) * (g.len() + 1));
+        assert_eq!(stats.misses, offsets.sum::<usize>() as u64);
+        assert_eq!(
+            stats_command(&rows, "1 tabulated"),
+            Ok(format!("21 arrangements, {stats}"))
+        );
+        assert!(stats_command(&rows, "3 memoized").is_err());
+    }
+}
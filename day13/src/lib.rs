@@ -0,0 +1,120 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    grid::Grid,
+    number::{add, mul, Uint},
+    parse::{blocks, ParseError},
+    solution::Solution,
+};
+
+type Block = Grid<char>;
+
+fn parse(input_str: &str) -> Result<Vec<Block>, ParseError> {
+    blocks(input_str)
+        .iter()
+        .map(|block| {
+            Grid::parse_lines(block, "`#` or `.`", |c| matches!(c, '#' | '.').then_some(c))
+        })
+        .collect()
+}
+
+fn diff(line1: &[char], line2: &[char]) -> usize {
+    line1
+        .iter()
+        .zip(line2.iter())
+        .filter(|(c1, c2)| c1 != c2)
+        .count()
+}
+
+fn check_mirror_correctness(block: &Block, lines_above: usize) -> usize {
+    let mut errors = 0;
+    if lines_above <= block.height() / 2 {
+        for i in 0..lines_above {
+            errors += diff(block.row(i), block.row(2 * lines_above - i - 1));
+        }
+    } else {
+        for i in lines_above..block.height() {
+            errors += diff(block.row(i), block.row(2 * lines_above - i - 1));
+        }
+    }
+    errors
+}
+
+fn mirror_sum(blocks: &[Block], error_count: usize) -> Uint {
+    let mut sum = 0;
+    'outer: for block in blocks {
+        for above in 1..block.height() {
+            if check_mirror_correctness(block, above) == error_count {
+                sum = add(sum, mul(100, above as Uint));
+                continue 'outer;
+            }
+        }
+        let block_t = block.transpose();
+        for above in 1..block_t.height() {
+            if check_mirror_correctness(&block_t, above) == error_count {
+                sum = add(sum, above as Uint);
+            }
+        }
+    }
+    sum
+}
+
+fn part1(blocks: &[Block]) -> Uint {
+    mirror_sum(blocks, 0)
+}
+
+fn part2(blocks: &[Block]) -> Uint {
+    mirror_sum(blocks, 1)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Block>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..##.
+#####.##.
+#####.##.
+..##..##.
+#....#..#
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day13::parse(EXAMPLE).unwrap()), 405);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&Day13::parse(EXAMPLE).unwrap()), 400);
+    }
+}
@@ -0,0 +1,185 @@
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Tile {
+    RoundRock,
+    CubeRock,
+    Empty,
+}
+use std::fmt::Display;
+
+use aoc_common::{
+    animation::{Animation, Color, Paint},
+    cycle::{nth_state, Strategy},
+    grid::{FromChar, Grid},
+    number::{mul, sum, Uint},
+    parse::ParseError,
+    solution::Solution,
+};
+use Tile::*;
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoundRock => write!(f, "O"),
+            CubeRock => write!(f, "#"),
+            Empty => write!(f, "."),
+        }
+    }
+}
+
+impl Paint for Tile {
+    fn color(&self) -> Option<Color> {
+        match self {
+            RoundRock => Some(Color::Yellow),
+            CubeRock => Some(Color::Gray),
+            Empty => None,
+        }
+    }
+}
+
+impl FromChar for Tile {
+    const EXPECTED: &'static str = "`O`, `#` or `.`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'O' => Some(RoundRock),
+            '#' => Some(CubeRock),
+            '.' => Some(Empty),
+            _ => None,
+        }
+    }
+}
+
+fn parse(input_str: &str) -> Result<Grid<Tile>, ParseError> {
+    input_str.parse()
+}
+
+fn tilt_north(map: &Grid<Tile>) -> Grid<Tile> {
+    let mut new_map = map.clone();
+    for ((l_idx, c_idx), tile) in map.iter() {
+        if tile == &RoundRock {
+            let mut found = false;
+            for i in (0..l_idx).rev() {
+                if matches!(new_map[(i, c_idx)], CubeRock | RoundRock) {
+                    if l_idx != i + 1 {
+                        new_map[(i + 1, c_idx)] = RoundRock;
+                        new_map[(l_idx, c_idx)] = Empty;
+                    }
+                    found = true;
+                    break;
+                }
+            }
+            if !found && l_idx != 0 {
+                new_map[(0, c_idx)] = RoundRock;
+                new_map[(l_idx, c_idx)] = Empty;
+            }
+        }
+    }
+    new_map
+}
+
+/// Moves every round rock with room above it one tile north, `None` once none of them can.
+fn roll_north_once(map: &Grid<Tile>) -> Option<Grid<Tile>> {
+    let mut new_map = map.clone();
+    let mut moved = false;
+    for ((l_idx, c_idx), tile) in map.iter() {
+        if tile == &RoundRock && l_idx != 0 && map[(l_idx - 1, c_idx)] == Empty {
+            new_map[(l_idx - 1, c_idx)] = RoundRock;
+            new_map[(l_idx, c_idx)] = Empty;
+            moved = true;
+        }
+    }
+    moved.then_some(new_map)
+}
+
+fn north_beam_support(map: &Grid<Tile>) -> Uint {
+    sum(map.rows().enumerate().map(|(l_idx, line)| {
+        let rocks = line.iter().filter(|t| t == &&RoundRock).count();
+        mul(rocks as Uint, (map.height() - l_idx) as Uint)
+    }))
+}
+
+fn part1(map: &Grid<Tile>) -> Uint {
+    north_beam_support(&tilt_north(map))
+}
+
+fn cycle_map(map: &Grid<Tile>) -> Grid<Tile> {
+    // tilt north, west, south and east by turning each of them to the top in turn
+    let mut current_map = map.clone();
+    for _ in 0..4 {
+        current_map = tilt_north(&current_map).rotate_clockwise();
+    }
+    current_map
+}
+
+fn animate_spin_cycle(map: &Grid<Tile>, animation: &mut Animation) {
+    let mut current_map = map.clone();
+    for turns in 0..4 {
+        loop {
+            let mut shown = current_map.clone();
+            for _ in 0..turns {
+                shown = shown.rotate_counterclockwise();
+            }
+            animation.frame(&shown);
+            match roll_north_once(&current_map) {
+                Some(rolled) => current_map = rolled,
+                None => break,
+            }
+        }
+        current_map = current_map.rotate_clockwise();
+    }
+}
+
+fn part2(map: &Grid<Tile>) -> Uint {
+    north_beam_support(&nth_state(map, cycle_map, 1_000_000_000, Strategy::Hash))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Grid<Tile>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(input))
+    }
+
+    fn animate(input: &Self::Input, animation: &mut Animation) {
+        animate_spin_cycle(input, animation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day14::parse(EXAMPLE).unwrap()), 136);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&Day14::parse(EXAMPLE).unwrap()), 64);
+    }
+}
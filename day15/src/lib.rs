@@ -0,0 +1,147 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    number::{add, mul, product, sum, Uint},
+    parse::{lines, Line, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+struct Lens {
+    label: String,
+    focal_length: usize,
+}
+
+impl PartialEq for Lens {
+    fn eq(&self, other: &Self) -> bool {
+        self.label.eq(&other.label)
+    }
+}
+
+fn check_step(line: &Line, step: &str) -> Result<(), ParseError> {
+    let operation_start = step
+        .find(['-', '='])
+        .ok_or_else(|| line.error(&step[step.len()..], "`-` or `=`"))?;
+    let (label, operation) = step.split_at(operation_start);
+    if label.is_empty() {
+        return Err(line.error(step, "a label"));
+    }
+    if !matches!(operation.as_bytes(), [b'-'] | [b'=', b'1'..=b'9']) {
+        return Err(line.error(operation, "`-` or `=<focal length>`"));
+    }
+    Ok(())
+}
+
+fn parse(input_str: &str) -> Result<Vec<String>, ParseError> {
+    lines(input_str)
+        .flat_map(|line| line.text.split(',').map(move |step| (line, step)))
+        .map(|(line, step)| {
+            check_step(&line, step)?;
+            Ok(step.to_string())
+        })
+        .collect()
+}
+
+fn hash(s: &str) -> u32 {
+    let mut current_value = 0;
+    for char in s.chars() {
+        current_value = add(current_value, char as u32);
+        current_value = mul(current_value, 17);
+        current_value %= 256;
+    }
+    current_value
+}
+
+fn part1(input: &[String]) -> Uint {
+    sum(input.iter().map(|s| Uint::from(hash(s))))
+}
+
+fn part2(input: &[String]) -> Uint {
+    let mut boxes: Vec<Vec<Lens>> = vec![vec![]; 256];
+    for item in input {
+        let label: String;
+        let operation: char;
+        let focal_length: usize;
+        if item.ends_with('-') {
+            operation = '-';
+            label = item.chars().take(item.len() - 1).collect();
+            focal_length = 0;
+        } else {
+            operation = '=';
+            label = item.chars().take(item.len() - 2).collect();
+            focal_length = item.chars().next_back().unwrap().to_digit(10).unwrap() as usize;
+        }
+        let box_idx = hash(&label) as usize;
+        let lens = Lens {
+            label,
+            focal_length,
+        };
+        let target_lens = boxes[box_idx]
+            .iter()
+            .enumerate()
+            .find(|(_, l)| l == &&lens)
+            .map(|(idx, _)| idx);
+        match operation {
+            '-' => {
+                if let Some(idx) = target_lens {
+                    boxes[box_idx].remove(idx);
+                }
+            }
+            _ => {
+                if let Some(idx) = target_lens {
+                    boxes[box_idx][idx] = lens;
+                } else {
+                    boxes[box_idx].push(lens);
+                }
+            }
+        }
+    }
+    let mut sum = 0;
+    for (box_idx, lens_box) in boxes.iter().enumerate() {
+        for (lens_idx, lens) in lens_box.iter().enumerate() {
+            sum = add(
+                sum,
+                product([lens.focal_length, lens_idx + 1, box_idx + 1].map(|n| n as Uint)),
+            );
+        }
+    }
+    sum
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<String>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day15::parse(EXAMPLE).unwrap()), 1320);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&Day15::parse(EXAMPLE).unwrap()), 145);
+    }
+}
@@ -0,0 +1,326 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    animation::{Animation, Color, Paint},
+    drawing::{Drawing, Rgb},
+    generate::{Options, Rng},
+    geometry::{
+        Direction::{self, *},
+        Point,
+    },
+    grid::Grid,
+    parallel,
+    parse::ParseError,
+    solution::Solution,
+};
+
+fn parse(input_str: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input_str, "a mirror, a splitter or `.`", |c| {
+        Some(match c {
+            '|' => Tile::new(NorthSouthSplitter),
+            '-' => Tile::new(WestEastSplitter),
+            '/' => Tile::new(UpMirror),
+            '\\' => Tile::new(DownMirror),
+            '.' => Tile::new(Empty),
+            _ => return None,
+        })
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct Tile {
+    tile_type: TileType,
+    /// The direction of the last beam that passed the tile.
+    energized: Option<Direction>,
+    /// One bit for every direction beams passed the tile in.
+    passed: u8,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.tile_type {
+            NorthSouthSplitter => write!(f, "|"),
+            WestEastSplitter => write!(f, "-"),
+            UpMirror => write!(f, "/"),
+            DownMirror => write!(f, "\\"),
+            Empty => {
+                if let Some(d) = self.energized {
+                    write!(f, "{d}")
+                } else {
+                    write!(f, ".")
+                }
+            }
+        }
+    }
+}
+
+impl Paint for Tile {
+    fn color(&self) -> Option<Color> {
+        match (self.energized, self.tile_type) {
+            (None, _) => None,
+            (Some(_), Empty) => Some(Color::Yellow),
+            (Some(_), _) => Some(Color::Red),
+        }
+    }
+}
+
+impl Tile {
+    fn new(tile_type: TileType) -> Self {
+        Self {
+            tile_type,
+            energized: None,
+            passed: 0,
+        }
+    }
+
+    fn was_passed(&self, direction: Direction) -> bool {
+        self.passed & 1 << direction as u8 != 0
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TileType {
+    NorthSouthSplitter,
+    WestEastSplitter,
+    UpMirror,
+    DownMirror,
+    Empty,
+}
+use TileType::*;
+
+#[derive(Debug, Clone)]
+struct Beam {
+    position: Point,
+    direction: Direction,
+}
+
+impl Beam {
+    fn step_in_direction(&mut self) {
+        self.position += self.direction.delta();
+    }
+
+    fn move_beam(&mut self, map: &mut Grid<Tile>) -> Option<Beam> {
+        let tile = map.get_signed_mut(self.position).unwrap();
+        tile.energized = Some(self.direction);
+        tile.passed |= 1 << self.direction as u8;
+        match tile.tile_type {
+            Empty => {
+                self.step_in_direction();
+                None
+            }
+            UpMirror => {
+                self.direction = if self.direction.is_vertical() {
+                    self.direction.turn_right()
+                } else {
+                    self.direction.turn_left()
+                };
+                self.step_in_direction();
+                None
+            }
+            DownMirror => {
+                self.direction = if self.direction.is_vertical() {
+                    self.direction.turn_left()
+                } else {
+                    self.direction.turn_right()
+                };
+                self.step_in_direction();
+                None
+            }
+            NorthSouthSplitter => {
+                if !self.direction.is_vertical() {
+                    self.direction = North;
+                    Some(Beam {
+                        position: self.position,
+                        direction: South,
+                    })
+                } else {
+                    self.step_in_direction();
+                    None
+                }
+            }
+            WestEastSplitter => {
+                if self.direction.is_vertical() {
+                    self.direction = West;
+                    Some(Beam {
+                        position: self.position,
+                        direction: East,
+                    })
+                } else {
+                    self.step_in_direction();
+                    None
+                }
+            }
+        }
+    }
+}
+
+const START_BEAM: Beam = Beam {
+    position: Point::ORIGIN,
+    direction: East,
+};
+
+/// Follows the beams until they leave the map or loop, calling `on_step` before every step, and
+/// returns the map with the energized tiles marked.
+fn energize(
+    map: &Grid<Tile>,
+    start_beam: Beam,
+    mut on_step: impl FnMut(&Grid<Tile>),
+) -> Grid<Tile> {
+    let mut map = map.clone();
+    let mut beams = vec![start_beam];
+    while !beams.is_empty() {
+        on_step(&map);
+        // remove out of bounds beams and beam that travel on already travelled routes
+        beams.retain(|beam| {
+            map.get_signed(beam.position)
+                .is_some_and(|tile| !tile.was_passed(beam.direction))
+        });
+        let mut new_beams = vec![];
+        for beam in &mut beams {
+            if let Some(b) = beam.move_beam(&mut map) {
+                new_beams.push(b);
+            }
+        }
+        beams.append(&mut new_beams);
+    }
+    map
+}
+
+fn calc_total_energized(map: &Grid<Tile>, start_beam: Beam) -> usize {
+    energize(map, start_beam, |_| {})
+        .cells()
+        .iter()
+        .filter(|t| t.energized.is_some())
+        .count()
+}
+
+fn part1(map: &Grid<Tile>) -> usize {
+    calc_total_energized(map, START_BEAM)
+}
+
+/// Beams entering the map from every edge tile.
+fn start_beams(map: &Grid<Tile>) -> Vec<Beam> {
+    let mut possible_start_beams = vec![];
+    for l in 0..map.height() {
+        possible_start_beams.push(Beam {
+            position: Point::new(l as isize, 0),
+            direction: East,
+        });
+        possible_start_beams.push(Beam {
+            position: Point::new(l as isize, map.width() as isize - 1),
+            direction: West,
+        });
+    }
+    for c in 0..map.width() {
+        possible_start_beams.push(Beam {
+            position: Point::new(0, c as isize),
+            direction: South,
+        });
+        possible_start_beams.push(Beam {
+            position: Point::new(map.height() as isize - 1, c as isize),
+            direction: North,
+        });
+    }
+    possible_start_beams
+}
+
+fn part2(map: &Grid<Tile>) -> usize {
+    parallel::solver_map(start_beams(map), |beam| calc_total_energized(map, beam))
+        .into_iter()
+        .max()
+        .unwrap()
+}
+
+/// Colours every tile by how many of the beams from [`start_beams`] energize it.
+fn heat_map(map: &Grid<Tile>) -> Grid<Rgb> {
+    let energized = parallel::solver_map(start_beams(map), |beam| energize(map, beam, |_| {}));
+    let heat = Grid::from_fn(map.width(), map.height(), |pos| {
+        energized
+            .iter()
+            .filter(|m| m[pos].energized.is_some())
+            .count()
+    });
+    let hottest = heat.cells().iter().max().copied().unwrap_or(0).max(1);
+    heat.map(|count| Rgb::heat(*count as f64 / hottest as f64))
+}
+
+/// A `size` by `size` contraption, a `density` share of whose tiles are mirrors or splitters.
+fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size.max(1);
+    let tiles = Grid::from_fn(size, size, |_| {
+        if rng.chance(options.density) {
+            *rng.choose(&['|', '-', '/', '\\'])
+        } else {
+            '.'
+        }
+    });
+    tiles.to_string() + "\n"
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Grid<Tile>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(input))
+    }
+
+    fn animate(input: &Self::Input, animation: &mut Animation) {
+        energize(input, START_BEAM, |map| animation.frame(map));
+    }
+
+    fn draw(input: &Self::Input) -> Option<Drawing> {
+        Some(Drawing::Grid(heat_map(input)))
+    }
+
+    fn generate(rng: &mut Rng, options: &Options) -> Option<String> {
+        Some(generate(rng, options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day16::parse(EXAMPLE).unwrap()), 46);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&Day16::parse(EXAMPLE).unwrap()), 51);
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let map = Day16::parse(&input).unwrap();
+            assert!((1..=400).contains(&part1(&map)));
+            assert!(part1(&map) <= part2(&map));
+        }
+    }
+}
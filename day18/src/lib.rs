@@ -0,0 +1,263 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    drawing::Drawing,
+    generate::{random_loop, Options, Rng},
+    geometry::{
+        Direction::{self, *},
+        Point,
+    },
+    number::{add, mul, sub, sum, Int},
+    parse::{lines, Line, ParseError},
+    solution::Solution,
+};
+
+fn direction_from_color(col: u32) -> Direction {
+    match col & 15 {
+        0 => East,
+        1 => South,
+        2 => West,
+        3 => North,
+        _ => unreachable!(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    direction: Direction,
+    distance: u32,
+    color: u32,
+}
+
+impl Instruction {
+    fn from_line(line: Line) -> Result<Self, ParseError> {
+        let mut iter = line.text.split_whitespace();
+        let direction = line.token(iter.next(), "a direction")?;
+        let distance = line.token(iter.next(), "a distance")?;
+        let color = line.token(iter.next(), "a color")?;
+        let hex_digits = color
+            .strip_prefix("(#")
+            .and_then(|c| c.strip_suffix(')'))
+            .filter(|c| c.len() == 6)
+            .ok_or_else(|| line.error(color, "a color like `(#70c710)`"))?;
+        let color = u32::from_str_radix(hex_digits, 16)
+            .map_err(|_| line.error(hex_digits, "six hex digits"))?;
+        if color & 15 > 3 {
+            return Err(line.error(&hex_digits[5..], "a direction digit between 0 and 3"));
+        }
+        Ok(Self {
+            direction: direction
+                .parse()
+                .ok()
+                .and_then(Direction::from_udlr)
+                .ok_or_else(|| line.error(direction, "`U`, `D`, `L` or `R`"))?,
+            distance: line.parse(distance, "a distance")?,
+            color,
+        })
+    }
+}
+
+fn parse(input_str: &str) -> Result<Vec<Instruction>, ParseError> {
+    lines(input_str).map(Instruction::from_line).collect()
+}
+
+fn shoelace(points: &[Point]) -> Int {
+    let mut prev_point = points.first().unwrap();
+    let mut total_area: Int = 0;
+    for point in points.iter().skip(1) {
+        // the area of the larger plans does not fit an `isize` on 32 bit targets
+        let (rows, cols) = (
+            add(prev_point.row as Int, point.row as Int),
+            sub(prev_point.col as Int, point.col as Int),
+        );
+        total_area = add(total_area, mul(rows, cols));
+        prev_point = point;
+    }
+    total_area.abs() / 2
+}
+
+/// The corners of the dug out trench, starting and ending at the origin.
+fn corner_positions(instructions: &[Instruction], part2: bool) -> Vec<Point> {
+    let mut corner_positions = vec![Point::ORIGIN];
+    let mut current_position = Point::ORIGIN;
+    let mut direction;
+    let mut distance;
+    for instruction in instructions {
+        if part2 {
+            direction = direction_from_color(instruction.color).delta();
+            distance = (instruction.color >> 4) as isize;
+        } else {
+            direction = instruction.direction.delta();
+            distance = instruction.distance as isize;
+        }
+        current_position += direction * distance;
+        corner_positions.push(current_position);
+    }
+    corner_positions
+}
+
+fn calc_area(instructions: &[Instruction], part2: bool) -> Int {
+    let corner_positions = corner_positions(instructions, part2);
+    let perimiter = sum(corner_positions
+        .windows(2)
+        .map(|w| w[0].manhattan_distance(w[1]) as Int));
+    add(shoelace(&corner_positions), add(perimiter / 2, 1))
+}
+
+fn part1(input: &[Instruction]) -> Int {
+    calc_area(input, false)
+}
+
+fn part2(input: &[Instruction]) -> Int {
+    calc_area(input, true)
+}
+
+/// A dig plan around a loop in a `size` by `size` area, spreading over a `density` share of it.
+/// The colours describe the same loop scaled up.
+fn generate(rng: &mut Rng, options: &Options) -> String {
+    let path = random_loop(rng, options.size, options.density);
+    let mut steps: Vec<(Direction, u32)> = vec![];
+    for (idx, &pos) in path.iter().enumerate() {
+        let direction = Direction::from_positions(pos, path[(idx + 1) % path.len()]);
+        match steps.last_mut() {
+            Some((last, distance)) if *last == direction => *distance += 1,
+            _ => steps.push((direction, 1)),
+        }
+    }
+    let longest = steps.iter().map(|(_, distance)| *distance).max().unwrap();
+    let scale = rng.range(1..=(0xfffff / longest).min(100_000) as i64) as u32;
+    let mut input = String::new();
+    for (direction, distance) in steps {
+        let (letter, digit) = match direction {
+            East => ('R', 0),
+            South => ('D', 1),
+            West => ('L', 2),
+            North => ('U', 3),
+        };
+        input += &format!("{letter} {distance} (#{:05x}{digit})\n", distance * scale);
+    }
+    input
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Vec<Instruction>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(input))
+    }
+
+    fn draw(input: &Self::Input) -> Option<Drawing> {
+        Some(Drawing::Polygon(corner_positions(input, false)))
+    }
+
+    fn generate(rng: &mut Rng, options: &Options) -> Option<String> {
+        Some(generate(rng, options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::generate::check_property;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day18::parse(EXAMPLE).unwrap()), 62);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&Day18::parse(EXAMPLE).unwrap()), 952408144115);
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let instructions = Day18::parse(&input).unwrap();
+            assert!(part1(&instructions) <= part2(&instructions));
+        }
+        // a ring around a single tile
+        let options = Options {
+            size: 3,
+            density: 0.0,
+        };
+        let instructions = Day18::parse(&generate(&mut Rng::new(0), &options)).unwrap();
+        assert_eq!(part1(&instructions), 9);
+    }
+
+    /// Digs the trench tile by tile and counts the tiles a flood fill from outside can't reach.
+    fn flood_fill_area(instructions: &[Instruction]) -> Int {
+        let mut trench = HashSet::from([Point::ORIGIN]);
+        let mut position = Point::ORIGIN;
+        for instruction in instructions {
+            for _ in 0..instruction.distance {
+                position += instruction.direction.delta();
+                trench.insert(position);
+            }
+        }
+        let min = trench.iter().fold(Point::ORIGIN, |a, b| {
+            Point::new(a.row.min(b.row), a.col.min(b.col))
+        });
+        let max = trench.iter().fold(Point::ORIGIN, |a, b| {
+            Point::new(a.row.max(b.row), a.col.max(b.col))
+        });
+        let (min, max) = (min - Point::new(1, 1), max + Point::new(1, 1));
+        let mut outside = HashSet::from([min]);
+        let mut queue = vec![min];
+        while let Some(point) = queue.pop() {
+            for direction in Direction::ALL {
+                let next = point + direction.delta();
+                let inside_box = (min.row..=max.row).contains(&next.row)
+                    && (min.col..=max.col).contains(&next.col);
+                if inside_box && !trench.contains(&next) && outside.insert(next) {
+                    queue.push(next);
+                }
+            }
+        }
+        ((max.row - min.row + 1) * (max.col - min.col + 1)) as Int - outside.len() as Int
+    }
+
+    #[test]
+    fn matches_flood_fill() {
+        check_property(50, |rng| {
+            let options = Options {
+                size: rng.below(30) + 3,
+                density: rng.range(0..=10) as f64 / 10.0,
+            };
+            let instructions = Day18::parse(&generate(rng, &options)).unwrap();
+            assert_eq!(part1(&instructions), flood_fill_area(&instructions));
+        });
+    }
+}
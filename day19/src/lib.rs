@@ -0,0 +1,369 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use aoc_common::{
+    combinator::{
+        blank_lines, delimited, lines_of, one_of, record, separated, tag, uint, word, Input, Parser,
+    },
+    generate::{Options, Rng},
+    number::{add, sum, Uint},
+    parse::ParseError,
+    repl::Command,
+    solution::{unsolvable, Solution},
+};
+
+#[derive(Debug, Clone)]
+pub struct Part {
+    values: HashMap<Feature, usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Feature {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Feature {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "x" => Some(Self::X),
+            "m" => Some(Self::M),
+            "a" => Some(Self::A),
+            "s" => Some(Self::S),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    target_feature: Feature,
+    operator: char,
+    threshold: usize,
+    target_workflow: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Workflow {
+    rules: Vec<Rule>,
+    final_workflow: String,
+}
+
+fn feature<'a>() -> impl Parser<'a, Feature> {
+    word().try_map("`x`, `m`, `a` or `s`", Feature::parse)
+}
+
+/// A workflow name, with where it is written.
+type Name<'a> = (&'a str, Input<'a>);
+
+/// One of the comma separated steps of a workflow: a rule like `a<2006:qkq`, or the name of the
+/// workflow to go to when no rule applies, which is the last step. Either way with the workflow
+/// it sends parts to.
+fn step<'a>() -> impl Parser<'a, (Result<Rule, String>, Name<'a>)> {
+    let condition = one_of("<>")
+        .then(uint().label("a threshold"))
+        .then_ignore(tag(":"))
+        .then(word().located());
+    // only a rule's name is followed by a condition
+    let rule =
+        feature()
+            .then(condition)
+            .map(|(target_feature, ((operator, threshold), target))| {
+                let rule = Rule {
+                    target_feature,
+                    operator,
+                    threshold,
+                    target_workflow: target.0.to_string(),
+                };
+                (Ok(rule), target)
+            });
+    move |input: Input<'a>| match word().then(one_of("<>")).parse_next(input) {
+        Ok(_) => rule.parse_next(input),
+        Err(_) => word()
+            .located()
+            .map(|target| (Err(target.0.to_string()), target))
+            .parse_next(input),
+    }
+}
+
+/// A workflow like `px{a<2006:qkq,m>2090:A,rfg}`, with the workflows it sends parts to.
+fn workflow<'a>() -> impl Parser<'a, (Name<'a>, Workflow, Vec<Name<'a>>)> {
+    let steps =
+        separated(step(), tag(",")).try_map("rules followed by the name of a workflow", |steps| {
+            let (mut steps, targets): (Vec<_>, Vec<_>) = steps.into_iter().unzip();
+            let final_workflow = steps.pop()?.err()?;
+            let rules = steps.into_iter().collect::<Result<_, _>>().ok()?;
+            let workflow = Workflow {
+                rules,
+                final_workflow,
+            };
+            Some((workflow, targets))
+        });
+    word()
+        .located()
+        .then(delimited(tag("{"), steps, tag("}")))
+        .map(|(name, (workflow, targets))| (name, workflow, targets))
+}
+
+/// A part like `{x=787,m=2655,a=1222,s=2876}`, rating each feature once.
+fn part<'a>() -> impl Parser<'a, Part> {
+    let ratings = record(feature().located(), uint().label("a rating"));
+    move |input: Input<'a>| {
+        let (ratings, rest) = ratings.parse_next(input)?;
+        let mut values = HashMap::new();
+        for ((feature, at), rating) in ratings {
+            if values.insert(feature, rating).is_some() {
+                return Err(at.fail("a feature that is not rated yet"));
+            }
+        }
+        if values.len() < 4 {
+            return Err(input.fail("ratings for `x`, `m`, `a` and `s`"));
+        }
+        Ok((Part { values }, rest))
+    }
+}
+
+/// Checks that every workflow is named once, that there is one named `in` and that every
+/// workflow only sends parts to other workflows, or to `A` or `R`.
+fn parse(input_str: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>), ParseError> {
+    let ((lines, start), parts) = lines_of(workflow())
+        .located()
+        .then_ignore(blank_lines())
+        .then(lines_of(part()))
+        .parse_all(input_str)?;
+    let mut workflows = HashMap::new();
+    let mut targets = vec![];
+    for ((name, at), workflow, sends_to) in lines {
+        if workflows.insert(name.to_string(), workflow).is_some() {
+            return Err(at.error("the name of another workflow"));
+        }
+        targets.extend(sends_to);
+    }
+    if !workflows.contains_key("in") {
+        return Err(start.error("a workflow named `in`"));
+    }
+    for (target, at) in targets {
+        if !matches!(target, "A" | "R") && !workflows.contains_key(target) {
+            return Err(at.error("`A`, `R` or a defined workflow"));
+        }
+    }
+    Ok((workflows, parts))
+}
+
+fn find_next_workflow(workflow: &Workflow, part: &Part) -> String {
+    for rule in &workflow.rules {
+        match rule.operator {
+            '<' => {
+                if part.values.get(&rule.target_feature).unwrap() < &rule.threshold {
+                    return rule.target_workflow.clone();
+                }
+            }
+            '>' => {
+                if part.values.get(&rule.target_feature).unwrap() > &rule.threshold {
+                    return rule.target_workflow.clone();
+                }
+            }
+            _ => panic!(),
+        }
+    }
+    workflow.final_workflow.clone()
+}
+
+/// The workflows a part goes through, from `in` to `A` if it is accepted or `R` if not. A part
+/// that comes back to a workflow would go round forever, which is an error.
+fn route(workflows: &HashMap<String, Workflow>, part: &Part) -> Result<Vec<String>, String> {
+    let mut route = vec!["in".to_string()];
+    while !matches!(route.last().unwrap().as_str(), "A" | "R") {
+        // `parse` checks that every workflow parts are sent to exists
+        let next = find_next_workflow(&workflows[route.last().unwrap()], part);
+        if route.contains(&next) {
+            return Err(format!("the part comes back to `{next}` and never leaves"));
+        }
+        route.push(next);
+    }
+    Ok(route)
+}
+
+/// The `route <part>` command of `aoc repl`.
+fn route_command(workflows: &HashMap<String, Workflow>, args: &str) -> Result<String, String> {
+    let part = part()
+        .parse_all(args)
+        .map_err(|e| format!("expected {}, found {}", e.expected, e.found))?;
+    Ok(route(workflows, &part)?.join(" -> "))
+}
+
+fn part1(workflows: &HashMap<String, Workflow>, parts: &[Part]) -> Result<Uint, String> {
+    let mut total = 0;
+    for part in parts {
+        if route(workflows, part)?.last().unwrap() == "A" {
+            total = add(total, sum(part.values.values().map(|v| *v as Uint)));
+        }
+    }
+    Ok(total)
+}
+
+/// `size` workflows and parts, every workflow has up to `density * 4` rules and only sends parts
+/// on to workflows after it, so every part ends up accepted or rejected.
+fn generate(rng: &mut Rng, options: &Options) -> String {
+    let count = options.size.max(1);
+    let mut names = vec!["in".to_string()];
+    let mut taken: HashSet<String> = names.iter().cloned().collect();
+    while names.len() < count {
+        let name: String = (0..rng.range(2..=3))
+            .map(|_| char::from(b'a' + rng.below(26) as u8))
+            .collect();
+        if taken.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    let max_rules = (options.density * 4.0).round() as i64;
+    let mut input = String::new();
+    for (idx, name) in names.iter().enumerate() {
+        let target = |rng: &mut Rng| {
+            let targets = names.len() - idx + 1;
+            match rng.below(targets) {
+                0 => "A",
+                1 => "R",
+                later => names[idx + later - 1].as_str(),
+            }
+        };
+        let mut rules: Vec<String> = vec![];
+        for _ in 0..rng.range(0..=max_rules) {
+            let feature = *rng.choose(&['x', 'm', 'a', 's']);
+            let operator = *rng.choose(&['<', '>']);
+            let threshold = rng.range(1..=4000);
+            rules.push(format!("{feature}{operator}{threshold}:{}", target(rng)));
+        }
+        rules.push(target(rng).to_string());
+        input += &format!("{name}{{{}}}\n", rules.join(","));
+    }
+    input.push('\n');
+    for _ in 0..count {
+        let [x, m, a, s] = [(); 4].map(|_| rng.range(1..=4000));
+        input += &format!("{{x={x},m={m},a={a},s={s}}}\n");
+    }
+    input
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = (HashMap<String, Workflow>, Vec<Part>);
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(&input.0, &input.1).unwrap_or_else(|e| unsolvable(e))
+    }
+
+    fn generate(rng: &mut Rng, options: &Options) -> Option<String> {
+        Some(generate(rng, options))
+    }
+
+    fn commands() -> Vec<Command<Self::Input>> {
+        vec![Command {
+            name: "route",
+            usage: "<part>",
+            help: "the workflows a part like `{x=787,m=2655,a=1222,s=2876}` goes through",
+            run: |(workflows, _), args| route_command(workflows, args),
+            words: |_| vec![],
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+
+    #[test]
+    fn part1_example() {
+        let (workflows, parts) = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&workflows, &parts), Ok(19114));
+    }
+
+    #[test]
+    fn routes_a_part() {
+        let (workflows, _) = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            route_command(&workflows, "{x=787,m=2655,a=1222,s=2876}"),
+            Ok("in -> qqz -> qs -> lnx -> A".to_string())
+        );
+        assert!(route_command(&workflows, "{x=787}").is_err());
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_rule() {
+        let error = |input: &str| Day19::parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("in{s<1351:px,qqz}\npx{a<2006:qkq,b>2090:A,rfg}\n\n{x=1,m=2,a=3,s=4}"),
+            "line 2, column 15: expected `x`, `m`, `a` or `s`, found `b`"
+        );
+        assert_eq!(
+            error("in{s<1351:px,qqz}\n\n{x=1,m=2,s=4}"),
+            "line 3, column 1: expected ratings for `x`, `m`, `a` and `s`, found `{`"
+        );
+        assert_eq!(
+            error("in{A}\n\n{x=1,m=2,a=3,s=4,m=5}"),
+            "line 3, column 18: expected a feature that is not rated yet, found `m`"
+        );
+        assert_eq!(
+            error("in{s<1351:px,R}\npx{a<2006:qkq,A}\n\n{x=1,m=2,a=3,s=4}"),
+            "line 2, column 11: expected `A`, `R` or a defined workflow, found `qkq`"
+        );
+        assert_eq!(
+            error("px{A}\n\n{x=1,m=2,a=3,s=4}"),
+            "line 1, column 1: expected a workflow named `in`, found `px`"
+        );
+        assert_eq!(
+            error("in{A}\nin{R}\n\n{x=1,m=2,a=3,s=4}"),
+            "line 2, column 1: expected the name of another workflow, found `in`"
+        );
+    }
+
+    #[test]
+    fn parts_going_round_are_errors() {
+        let (workflows, parts) =
+            Day19::parse("in{x<10:px,A}\npx{in}\n\n{x=1,m=2,a=3,s=4}").unwrap();
+        let error = "the part comes back to `in` and never leaves".to_string();
+        assert_eq!(part1(&workflows, &parts), Err(error.clone()));
+        assert_eq!(route_command(&workflows, "{x=1,m=2,a=3,s=4}"), Err(error));
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let (workflows, parts) = Day19::parse(&input).unwrap();
+            assert_eq!((workflows.len(), parts.len()), (20, 20));
+            let total: usize = parts.iter().flat_map(|p| p.values.values()).sum();
+            assert!(part1(&workflows, &parts).unwrap() <= total as Uint);
+        }
+    }
+}
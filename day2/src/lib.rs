@@ -0,0 +1,174 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    combinator::{header, lines_of, separated, spaces, tag, uint, Parser},
+    generate::{Options, Rng},
+    number::{product, sum, Uint},
+    parse::ParseError,
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+struct Draw {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl Draw {
+    /// Cube counts like `3 blue, 4 red`.
+    fn parser<'a>() -> impl Parser<'a, Self> {
+        let color = tag("red")
+            .or(tag("green"))
+            .or(tag("blue"))
+            .label("`red`, `green` or `blue`");
+        let cubes = uint()
+            .label("a cube count")
+            .then_ignore(tag(" "))
+            .then(color);
+        separated(cubes, tag(",").then(spaces())).map(|cubes| {
+            let mut result = Self {
+                red: 0,
+                green: 0,
+                blue: 0,
+            };
+            for (count, color) in cubes {
+                match color {
+                    "red" => result.red = count,
+                    "green" => result.green = count,
+                    _ => result.blue = count,
+                }
+            }
+            result
+        })
+    }
+
+    fn is_possible(&self) -> bool {
+        if self.red > 12 || self.green > 13 || self.blue > 14 {
+            return false;
+        }
+        true
+    }
+    fn as_tuple(&self) -> (u32, u32, u32) {
+        (self.red, self.green, self.blue)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    id: u32,
+    draws: Vec<Draw>,
+}
+
+impl Game {
+    fn parser<'a>() -> impl Parser<'a, Self> {
+        let id = tag("Game ").ignore_then(uint().label("a game id"));
+        let draws = separated(Draw::parser(), tag(";").then(spaces()));
+        header(id, draws).map(|(id, draws)| Self { id, draws })
+    }
+
+    fn is_possible(&self) -> bool {
+        self.draws.iter().filter(|draw| draw.is_possible()).count() == self.draws.len()
+    }
+
+    fn power(&self) -> Uint {
+        let tuple_draws: Vec<_> = self.draws.iter().map(|draw| draw.as_tuple()).collect();
+        product(
+            [
+                tuple_draws.iter().map(|(r, _, _)| *r).max().unwrap_or(0),
+                tuple_draws.iter().map(|(_, g, _)| *g).max().unwrap_or(0),
+                tuple_draws.iter().map(|(_, _, b)| *b).max().unwrap_or(0),
+            ]
+            .map(Uint::from),
+        )
+    }
+}
+
+fn part1(games: &[Game]) -> Uint {
+    sum(games
+        .iter()
+        .filter(|game| game.is_possible())
+        .map(|game| Uint::from(game.id)))
+}
+
+fn part2(games: &[Game]) -> Uint {
+    sum(games.iter().map(|game| game.power()))
+}
+
+/// `size` games whose cube counts go up to about `density * 20`.
+fn generate(rng: &mut Rng, options: &Options) -> String {
+    let max_count = 1 + (options.density * 19.0) as i64;
+    let mut input = String::new();
+    for id in 1..=options.size {
+        let draws: Vec<String> = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let shown = rng.range(1..=3) as usize;
+                colors[..shown]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.range(1..=max_count)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        input += &format!("Game {id}: {}\n", draws.join("; "));
+    }
+    input
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        lines_of(Game::parser()).parse_all(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(input))
+    }
+
+    fn generate(rng: &mut Rng, options: &Options) -> Option<String> {
+        Some(generate(rng, options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day2::parse(EXAMPLE).unwrap()), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&Day2::parse(EXAMPLE).unwrap()), 2286);
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let games = Day2::parse(&input).unwrap();
+            assert_eq!(games.len(), 20);
+            assert!(part1(&games) <= (1..=20).sum());
+        }
+    }
+}
@@ -0,0 +1,161 @@
+#[derive(Debug, PartialEq)]
+pub enum Tile {
+    Start,
+    Plot,
+    Rock,
+}
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_common::{
+    animation::{Animation, Color, Paint},
+    drawing::{paint, Drawing},
+    grid::{FromChar, Grid},
+    parse::ParseError,
+    solution::Solution,
+    Position,
+};
+use Tile::*;
+
+impl FromChar for Tile {
+    const EXPECTED: &'static str = "`S`, `#` or `.`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'S' => Some(Start),
+            '#' => Some(Rock),
+            '.' => Some(Plot),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rock => write!(f, "#"),
+            Start => write!(f, "S"),
+            Plot => write!(f, "."),
+        }
+    }
+}
+
+fn parse(input_str: &str) -> Result<Grid<Tile>, ParseError> {
+    input_str.parse()
+}
+
+fn get_neighboring_plots(map: &Grid<Tile>, pos: Position) -> impl Iterator<Item = Position> + '_ {
+    map.neighbours(pos).filter(|&pos| map[pos] != Rock)
+}
+
+/// A tile of the map as shown in an animation, with the plots reached so far marked.
+enum Shown<'a> {
+    Tile(&'a Tile),
+    Reached,
+}
+
+impl Display for Shown<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Shown::Tile(tile) => write!(f, "{tile}"),
+            Shown::Reached => write!(f, "O"),
+        }
+    }
+}
+
+impl Paint for Shown<'_> {
+    fn color(&self) -> Option<Color> {
+        match self {
+            Shown::Tile(Rock) => Some(Color::Gray),
+            Shown::Tile(_) => None,
+            Shown::Reached => Some(Color::Green),
+        }
+    }
+}
+
+fn show_positions<'a>(map: &'a Grid<Tile>, positions: &HashSet<Position>) -> Grid<Shown<'a>> {
+    Grid::from_fn(map.width(), map.height(), |pos| {
+        if positions.contains(&pos) {
+            Shown::Reached
+        } else {
+            Shown::Tile(&map[pos])
+        }
+    })
+}
+
+/// Counts the plots reachable in exactly `iterations` steps, calling `on_step` with the plots
+/// reached after every step.
+fn reachable_plots(
+    map: &Grid<Tile>,
+    iterations: usize,
+    mut on_step: impl FnMut(&HashSet<Position>),
+) -> usize {
+    let mut current_positions = HashSet::new();
+    current_positions.extend(map.position(|t| t == &Start));
+    on_step(&current_positions);
+    for _ in 0..iterations {
+        let mut new_positions = HashSet::new();
+        for pos in &current_positions {
+            new_positions.extend(get_neighboring_plots(map, *pos));
+        }
+        current_positions = new_positions;
+        on_step(&current_positions);
+    }
+    current_positions.len()
+}
+
+fn part1(map: &Grid<Tile>, iterations: usize) -> usize {
+    reachable_plots(map, iterations, |_| {})
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = Grid<Tile>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input, 64)
+    }
+
+    fn animate(input: &Self::Input, animation: &mut Animation) {
+        reachable_plots(input, 64, |positions| {
+            animation.frame(&show_positions(input, positions))
+        });
+    }
+
+    fn draw(input: &Self::Input) -> Option<Drawing> {
+        let mut frames = vec![];
+        reachable_plots(input, 64, |positions| {
+            frames.push(paint(&show_positions(input, positions)))
+        });
+        Some(Drawing::Frames(frames))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day21::parse(EXAMPLE).unwrap(), 6), 16);
+    }
+}
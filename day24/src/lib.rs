@@ -0,0 +1,167 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    combinator::{int, lines_of, spaces, tag, Parser},
+    generate::{Options, Rng},
+    number::{mul, sub, Int},
+    parallel,
+    parse::ParseError,
+    solution::Solution,
+};
+
+/// A hailstone seen from above, as only part 1 is solved, which ignores the `z` axis.
+#[derive(Debug, Clone)]
+pub struct Hailstone {
+    px: Int,
+    py: Int,
+    vx: Int,
+    vy: Int,
+}
+
+type Position = (f64, f64);
+
+impl Hailstone {
+    /// A hailstone like `19, 13, 30 @ -2,  1, -2`, whose `z` values are checked but not kept.
+    fn parser<'a>() -> impl Parser<'a, Self> {
+        triple("a position coordinate")
+            .then_ignore(spaces().then(tag("@")).then(spaces()))
+            .then(triple("a velocity component"))
+            .map(|([px, py, _], [vx, vy, _])| Self { px, py, vx, vy })
+    }
+
+    fn get_intersection(&self, other: &Hailstone) -> Option<Position> {
+        let denominator = sub(mul(other.vx, self.vy), mul(self.vx, other.vy));
+        if denominator == 0 {
+            return None;
+        }
+        let (dx, dy) = (sub(other.px, self.px), sub(other.py, self.py));
+        let t1 = sub(mul(other.vx, dy), mul(dx, other.vy)) as f64 / denominator as f64;
+        let t2 = sub(mul(dy, self.vx), mul(self.vy, dx)) as f64 / denominator as f64;
+        let pos1 = (
+            self.px as f64 + self.vx as f64 * t1,
+            self.py as f64 + self.vy as f64 * t1,
+        );
+        if t1 >= 0. && t2 >= 0. {
+            Some(pos1)
+        } else {
+            None
+        }
+    }
+}
+
+fn triple<'a>(expected: &'static str) -> impl Parser<'a, [Int; 3]> {
+    let value = move || int().label(expected);
+    let comma = || tag(",").then(spaces());
+    value()
+        .then_ignore(comma())
+        .then(value())
+        .then_ignore(comma())
+        .then(value())
+        .map(|((x, y), z)| [x, y, z])
+}
+
+fn parse(input_str: &str) -> Result<Vec<Hailstone>, ParseError> {
+    lines_of(Hailstone::parser()).parse_all(input_str)
+}
+
+fn check_bounds(pos: &Position, lower_bound: f64, upper_bound: f64) -> bool {
+    pos.0 >= lower_bound && pos.0 <= upper_bound && pos.1 >= lower_bound && pos.1 <= upper_bound
+}
+
+fn count_intersections(hailstones: &[Hailstone], lower_bound: f64, upper_bound: f64) -> usize {
+    let counts = parallel::solver_map(hailstones.iter().enumerate().collect(), |(h1_idx, h1)| {
+        hailstones
+            .iter()
+            .skip(h1_idx)
+            .map(|h2| {
+                if let Some(pos) = h1.get_intersection(h2) {
+                    if check_bounds(&pos, lower_bound, upper_bound) {
+                        1
+                    } else {
+                        0
+                    }
+                } else {
+                    0
+                }
+            })
+            .sum::<usize>()
+    });
+    counts.into_iter().sum()
+}
+
+fn part1(hailstones: &[Hailstone]) -> usize {
+    count_intersections(hailstones, 200000000000000., 400000000000000.)
+}
+
+/// `size` hailstones around the test area, each moving parallel to an earlier one with
+/// probability `density`.
+fn generate(rng: &mut Rng, options: &Options) -> String {
+    // parallel hailstones scale one of the velocities picked at random, which are never scaled
+    // again so that they cannot grow out of bounds
+    let mut velocities: Vec<[i64; 3]> = vec![];
+    let mut input = String::new();
+    for _ in 0..options.size {
+        let velocity = if velocities.is_empty() || !rng.chance(options.density) {
+            let velocity = [(); 3].map(|_| rng.range(-500..=500));
+            velocities.push(velocity);
+            velocity
+        } else {
+            let factor = *rng.choose(&[-2, -1, 2]);
+            rng.choose(&velocities).map(|v| v * factor)
+        };
+        let [px, py, pz] = [(); 3].map(|_| rng.range(100_000_000_000_000..=500_000_000_000_000));
+        let [vx, vy, vz] = velocity;
+        input += &format!("{px}, {py}, {pz} @ {vx}, {vy}, {vz}\n");
+    }
+    input
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Vec<Hailstone>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn generate(rng: &mut Rng, options: &Options) -> Option<String> {
+        Some(generate(rng, options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            count_intersections(&Day24::parse(EXAMPLE).unwrap(), 7., 27.),
+            2
+        );
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let hailstones = Day24::parse(&input).unwrap();
+            assert_eq!(hailstones.len(), 20);
+            assert!(part1(&hailstones) <= 20 * 19 / 2);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    generate::{Options, Rng},
+    number::{product, sum, Uint},
+    parse::{lines, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl Position {
+    fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    fn is_adjacent(&self, other: &Position) -> bool {
+        (self.x as isize - other.x as isize).abs() <= 1
+            && (self.y as isize - other.y as isize).abs() <= 1
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    symbol: char,
+    position: Position,
+}
+
+impl Symbol {
+    fn new(symbol: char, position: Position) -> Self {
+        Self { symbol, position }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Number {
+    value: Uint,
+    positions: Vec<Position>,
+}
+
+impl Number {
+    fn is_adjacent(&self, position: &Position) -> bool {
+        self.positions.iter().any(|p| p.is_adjacent(position))
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Symbol>, Vec<Number>), ParseError> {
+    let mut symbols: Vec<Symbol> = vec![];
+    let mut numbers: Vec<Number> = vec![];
+    for line in lines(input) {
+        let y = line.number - 1;
+        // the column and byte offset the number being read starts at
+        let mut number_start = None;
+        // `None` past the end of the line, which ends a number there
+        let chars = line.text.char_indices().map(Some).chain([None]);
+        for (x, char) in chars.enumerate() {
+            match char {
+                Some((offset, '0'..='9')) => {
+                    number_start.get_or_insert((x, offset));
+                }
+                _ => {
+                    if let Some((start, offset)) = number_start.take() {
+                        let end = char.map_or(line.text.len(), |(end, _)| end);
+                        numbers.push(Number {
+                            value: line.parse(&line.text[offset..end], "a number")?,
+                            positions: (start..x).map(|x| Position::new(x, y)).collect(),
+                        });
+                    }
+                    if let Some((_, symbol)) = char.filter(|(_, c)| *c != '.') {
+                        symbols.push(Symbol::new(symbol, Position::new(x, y)))
+                    }
+                }
+            }
+        }
+    }
+    Ok((symbols, numbers))
+}
+
+fn part1(symbols: &[Symbol], numbers: &[Number]) -> Uint {
+    sum(numbers
+        .iter()
+        .filter(|number| {
+            symbols
+                .iter()
+                .map(|s| &s.position)
+                .any(|s| number.is_adjacent(s))
+        })
+        .map(|num| num.value))
+}
+
+fn part2(symbols: &[Symbol], numbers: &[Number]) -> Uint {
+    sum(symbols
+        .iter()
+        .filter(|symbol| symbol.symbol == '*')
+        .map(|s| numbers.iter().filter(|n| n.is_adjacent(&s.position)))
+        .filter(|adj_num| adj_num.clone().count() == 2)
+        .map(|adj_num| product(adj_num.map(|n| n.value))))
+}
+
+/// A `size` by `size` schematic, `density` is roughly the share of tiles covered by numbers.
+fn generate(rng: &mut Rng, options: &Options) -> String {
+    const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '=', '%', '@', '&', '-'];
+    let mut input = String::new();
+    for _ in 0..options.size {
+        let mut row = String::new();
+        while row.len() < options.size {
+            let left = options.size - row.len();
+            if rng.chance(options.density / 2.0) {
+                for _ in 0..rng.range(1..=3).min(left as i64) {
+                    row.push(char::from(b'0' + rng.below(10) as u8));
+                }
+                if row.len() < options.size {
+                    row.push('.');
+                }
+            } else if rng.chance(options.density / 4.0) {
+                row.push(*rng.choose(&SYMBOLS));
+            } else {
+                row.push('.');
+            }
+        }
+        input += &row;
+        input.push('\n');
+    }
+    input
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = (Vec<Symbol>, Vec<Number>);
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(&input.0, &input.1))
+    }
+
+    fn generate(rng: &mut Rng, options: &Options) -> Option<String> {
+        Some(generate(rng, options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn part1_example() {
+        let (symbols, numbers) = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&symbols, &numbers), 4361);
+    }
+
+    #[test]
+    fn part2_example() {
+        let (symbols, numbers) = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&symbols, &numbers), 467835);
+    }
+
+    #[test]
+    fn numbers_too_large_are_errors() {
+        assert_eq!(
+            Day3::parse("..*\n.123456789012345678901234567890123456789012345\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 2: expected a number, found `123456789012345678901234567890123456789012345`"
+        );
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            assert!(input.lines().all(|line| line.len() == 20));
+            let (symbols, numbers) = Day3::parse(&input).unwrap();
+            assert!(numbers.iter().all(|n| n.positions.len() <= 3));
+            let digits = input.chars().filter(char::is_ascii_digit).count();
+            assert_eq!(
+                numbers.iter().map(|n| n.positions.len()).sum::<usize>(),
+                digits
+            );
+            assert_eq!(symbols.len(), 400 - digits - input.matches('.').count());
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_common::{
+    combinator::{header, lines_of, list, spaces, tag, uint, Parser},
+    number::{add, pow, sum, Uint},
+    parse::ParseError,
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Card {
+    id: u32,
+    winning_numbers: Vec<u32>,
+    have_numbers: Vec<u32>,
+}
+
+impl Card {
+    /// A card like `Card 1: 41 48 83 | 83 86  6`.
+    fn parser<'a>() -> impl Parser<'a, Self> {
+        let id = tag("Card")
+            .then(spaces())
+            .ignore_then(uint().label("a card id"));
+        let numbers = || list(uint().label("a number"));
+        let separator = spaces().then(tag("|")).then(spaces());
+        header(id, numbers().then_ignore(separator).then(numbers())).map(
+            |(id, (winning_numbers, have_numbers))| Self {
+                id,
+                winning_numbers,
+                have_numbers,
+            },
+        )
+    }
+    fn points_worth(&self) -> u32 {
+        self.have_numbers
+            .iter()
+            .filter(|n| self.winning_numbers.contains(n))
+            .count() as u32
+    }
+}
+
+fn part1(cards: &[Card]) -> Uint {
+    sum(cards.iter().map(|card| match card.points_worth() {
+        0 => 0,
+        c => pow(2, c - 1),
+    }))
+}
+
+fn part2(cards: &[Card]) -> Uint {
+    let mut amounts: HashMap<u32, Uint> = HashMap::from_iter(cards.iter().map(|card| (card.id, 1)));
+    for card in cards {
+        let card_amount = *amounts.get(&card.id).unwrap();
+        for i in card.id + 1..=card.id + card.points_worth() {
+            if let Some(amount) = amounts.get_mut(&i) {
+                *amount = add(*amount, card_amount);
+            }
+        }
+    }
+    sum(amounts.values().copied())
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<Card>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        lines_of(Card::parser()).parse_all(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day4::parse(EXAMPLE).unwrap()), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&Day4::parse(EXAMPLE).unwrap()), 30);
+    }
+}
@@ -0,0 +1,365 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    combinator::{
+        blank_lines, blocks_of, following_lines, header, list, tag, take_while1, uint, Input,
+        Parser,
+    },
+    generate::{Options, Rng},
+    number::{add, sub, Uint},
+    parallel,
+    parse::ParseError,
+    repl::Command,
+    solution::{unsolvable, Solution},
+};
+
+#[derive(Debug)]
+pub struct Map {
+    /// Like `seed-to-soil`.
+    name: String,
+    maps: Vec<(Uint, Uint, Uint)>,
+}
+
+impl Map {
+    /// A block like `seed-to-soil map:` followed by one range per line.
+    fn parser<'a>() -> impl Parser<'a, Self> {
+        let name = take_while1("a map name", |c| {
+            c.is_alphanumeric() || matches!(c, '-' | ' ')
+        });
+        let range = uint()
+            .label("a destination range start")
+            .then_ignore(tag(" "))
+            .then(uint().label("a source range start"))
+            .then_ignore(tag(" "))
+            .then(uint().label("a range length"))
+            .map(|((dst, src), len)| (dst, src, len));
+        header(name, following_lines(range)).map(|(name, maps)| Self {
+            name: name.trim_end_matches(" map").to_string(),
+            maps,
+        })
+    }
+    fn get_mapped_value(&self, src: Uint) -> Uint {
+        for map in &self.maps {
+            if src >= map.1 && sub(src, map.1) < map.2 {
+                return add(map.0, sub(src, map.1));
+            }
+        }
+        src
+    }
+
+    /// Maps every value in the ranges `start..end`, splitting them where they overlap the ends
+    /// of the map's ranges.
+    fn get_mapped_ranges(&self, mut unmapped: Vec<(Uint, Uint)>) -> Vec<(Uint, Uint)> {
+        let mut mapped = vec![];
+        for &(dst, src, len) in &self.maps {
+            let end = add(src, len);
+            let mut rest = vec![];
+            for (start, stop) in unmapped {
+                let (overlap_start, overlap_stop) = (start.max(src), stop.min(end));
+                if overlap_start >= overlap_stop {
+                    rest.push((start, stop));
+                    continue;
+                }
+                mapped.push((
+                    add(sub(overlap_start, src), dst),
+                    add(sub(overlap_stop, src), dst),
+                ));
+                if start < overlap_start {
+                    rest.push((start, overlap_start));
+                }
+                if overlap_stop < stop {
+                    rest.push((overlap_stop, stop));
+                }
+            }
+            unmapped = rest;
+        }
+        mapped.extend(unmapped);
+        mapped
+    }
+}
+
+/// The seed numbers, which part 2 reads as pairs of the start and the length of a range.
+fn seeds<'a>() -> impl Parser<'a, Vec<Uint>> {
+    let numbers = list(uint().label("a seed number").located());
+    move |input: Input<'a>| {
+        let (numbers, rest) = numbers.parse_next(input)?;
+        if let [.., (_, last)] = numbers[..] {
+            if numbers.len() % 2 == 1 {
+                return Err(last.fail("a seed number followed by the length of its range"));
+            }
+        }
+        Ok((numbers.into_iter().map(|(seed, _)| seed).collect(), rest))
+    }
+}
+
+fn parse(input: &str) -> Result<(Vec<Uint>, Vec<Map>), ParseError> {
+    header(tag("seeds"), seeds())
+        .map(|(_, seeds)| seeds)
+        .then_ignore(blank_lines())
+        .then(blocks_of(Map::parser()))
+        .parse_all(input)
+}
+
+fn map_to_end(seed: Uint, maps: &[Map]) -> Uint {
+    let mut current_val = seed;
+    for map in maps {
+        current_val = map.get_mapped_value(current_val);
+    }
+    current_val
+}
+
+fn part1(seeds: &[Uint], maps: &[Map]) -> Result<Uint, String> {
+    seeds
+        .iter()
+        .map(|seed| map_to_end(*seed, maps))
+        .min()
+        .ok_or_else(|| "there are no seeds".to_string())
+}
+
+fn part2(seeds: &[Uint], maps: &[Map]) -> Result<Uint, String> {
+    // every seed range is mapped on its own, its pieces never meeting those of the others
+    let lowest = parallel::solver_map(seeds.chunks_exact(2).collect(), |pair| {
+        let mut ranges = vec![(pair[0], add(pair[0], pair[1]))];
+        ranges.retain(|(start, end)| start < end);
+        for map in maps {
+            ranges = map.get_mapped_ranges(ranges);
+        }
+        ranges.iter().map(|(start, _)| *start).min()
+    });
+    lowest
+        .into_iter()
+        .flatten()
+        .min()
+        .ok_or_else(|| "every seed range is empty".to_string())
+}
+
+/// An almanac with values below `size * 100` whose maps have up to `size` ranges, each covering
+/// a `density` share of the values.
+fn generate(rng: &mut Rng, options: &Options) -> String {
+    const CATEGORIES: [&str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+    let limit = options.size.max(1) as i64 * 100;
+    let seeds: Vec<String> = (0..4)
+        .flat_map(|_| [rng.range(0..=limit - 1), rng.range(1..=limit / 10)])
+        .map(|n| n.to_string())
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+    for names in CATEGORIES.windows(2) {
+        let mut cuts: Vec<i64> = (1..options.size)
+            .map(|_| rng.range(1..=limit - 1))
+            .chain([0, limit])
+            .collect();
+        cuts.sort();
+        cuts.dedup();
+        let mut ranges: Vec<String> = cuts
+            .windows(2)
+            .filter_map(|w| {
+                let destination = rng.range(0..=limit - 1);
+                rng.chance(options.density)
+                    .then(|| format!("{destination} {} {}", w[0], w[1] - w[0]))
+            })
+            .collect();
+        rng.shuffle(&mut ranges);
+        input += &format!("\n{}-to-{} map:\n", names[0], names[1]);
+        for range in ranges {
+            input += &range;
+            input.push('\n');
+        }
+    }
+    input
+}
+
+/// The `map <seed>` command of `aoc repl`, showing the value of every category on the way.
+fn map_command(maps: &[Map], args: &str) -> Result<String, String> {
+    let seed: Uint = args
+        .parse()
+        .map_err(|_| format!("expected a seed number, found `{args}`"))?;
+    let mut steps = vec![];
+    let mut value = seed;
+    for map in maps {
+        let (from, to) = map.name.split_once("-to-").unwrap_or(("", &map.name));
+        if steps.is_empty() {
+            steps.push(format!("{from} {value}"));
+        }
+        value = map.get_mapped_value(value);
+        steps.push(format!("{to} {value}"));
+    }
+    Ok(steps.join(", "))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = (Vec<Uint>, Vec<Map>);
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(&input.0, &input.1).unwrap_or_else(|e| unsolvable(e))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(&input.0, &input.1).unwrap_or_else(|e| unsolvable(e)))
+    }
+
+    fn generate(rng: &mut Rng, options: &Options) -> Option<String> {
+        Some(generate(rng, options))
+    }
+
+    fn commands() -> Vec<Command<Self::Input>> {
+        vec![Command {
+            name: "map",
+            usage: "<seed>",
+            help: "the value of every category a seed maps to",
+            run: |(_, maps), args| map_command(maps, args),
+            words: |(seeds, _)| seeds.iter().map(Uint::to_string).collect(),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::check_property;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn part1_example() {
+        let (seeds, maps) = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&seeds, &maps), Ok(35));
+    }
+
+    #[test]
+    fn part2_example() {
+        let (seeds, maps) = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&seeds, &maps), Ok(46));
+    }
+
+    #[test]
+    fn seeds_come_in_pairs() {
+        let error = |input: &str| Day5::parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n"),
+            "line 1, column 14: expected a seed number followed by the length of its range, \
+            found `55`"
+        );
+        assert_eq!(
+            error("seeds:\n\nseed-to-soil map:\n50 98 2\n"),
+            "line 1, column 7: expected a seed number, found end of line"
+        );
+        let (seeds, maps) = Day5::parse("seeds: 79 0\n\nseed-to-soil map:\n50 98 2\n").unwrap();
+        assert_eq!(
+            part2(&seeds, &maps),
+            Err("every seed range is empty".to_string())
+        );
+    }
+
+    #[test]
+    fn maps_a_seed() {
+        let (_, maps) = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            map_command(&maps, "79"),
+            Ok(
+                "seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, \
+                humidity 78, location 82"
+                    .to_string()
+            )
+        );
+        assert!(map_command(&maps, "seventy").is_err());
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), &Options::default());
+            let (seeds, maps) = Day5::parse(&input).unwrap();
+            assert_eq!((seeds.len(), maps.len()), (8, 7));
+            let lowest = part2(&seeds, &maps).unwrap();
+            assert!(seeds
+                .iter()
+                .step_by(2)
+                .all(|s| lowest <= map_to_end(*s, &maps)));
+        }
+    }
+
+    fn look_up(value: Uint, maps: &[Map]) -> Uint {
+        maps.iter().fold(value, |value, map| {
+            map.maps
+                .iter()
+                .copied()
+                .find(|&(_, src, len)| src <= value && value < src + len)
+                .map_or(value, |(dst, src, _)| dst + value - src)
+        })
+    }
+
+    /// Looks every seed of every range up on its own.
+    fn brute_force_part2(seeds: &[Uint], maps: &[Map]) -> Uint {
+        seeds
+            .chunks_exact(2)
+            .flat_map(|pair| pair[0]..pair[0] + pair[1])
+            .map(|seed| look_up(seed, maps))
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        check_property(50, |rng| {
+            let options = Options {
+                size: rng.below(10) + 1,
+                density: rng.range(0..=10) as f64 / 10.0,
+            };
+            let (seeds, maps) = Day5::parse(&generate(rng, &options)).unwrap();
+            let lowest = seeds.iter().map(|s| look_up(*s, &maps)).min();
+            assert_eq!(part1(&seeds, &maps).ok(), lowest);
+            assert_eq!(part2(&seeds, &maps), Ok(brute_force_part2(&seeds, &maps)));
+        });
+    }
+}
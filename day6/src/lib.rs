@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    number::{add, mul, pow, product, sub, Uint},
+    parse::{lines, Line, ParseError},
+    solution::Solution,
+};
+
+fn get_numbers_from_line(line: &Line) -> Result<Vec<Uint>, ParseError> {
+    line.text
+        .split(' ')
+        .skip(1)
+        .map(|num| num.trim())
+        .filter(|num| !num.is_empty())
+        .map(|num| line.parse(num, "a number"))
+        .collect()
+}
+
+fn get_distance_for_hold_time(hold_time: Uint, total_time: Uint) -> Uint {
+    mul(sub(total_time, hold_time), hold_time)
+}
+
+/// Counts the hold times beating the record. The distance grows up to holding half of the race
+/// and shrinks the same way after, so we binary search the shortest winning hold time.
+fn count_ways_to_win(total_time: Uint, record: Uint) -> Uint {
+    let wins = |hold_time| get_distance_for_hold_time(hold_time, total_time) > record;
+    let half = total_time / 2;
+    if !wins(half) {
+        return 0;
+    }
+    let (mut lo, mut hi) = (0, half);
+    while lo < hi {
+        let mid = add(lo, sub(hi, lo) / 2);
+        if wins(mid) {
+            hi = mid;
+        } else {
+            lo = add(mid, 1);
+        }
+    }
+    add(sub(total_time, mul(2, lo)), 1)
+}
+
+fn part1(times: &[Uint], distances: &[Uint]) -> Uint {
+    product(
+        times
+            .iter()
+            .zip(distances)
+            .map(|(time, distance)| count_ways_to_win(*time, *distance)),
+    )
+}
+
+fn concat_numbers(numbers: &[Uint]) -> Uint {
+    numbers.iter().fold(0, |concatenated, number| {
+        let digits = number.to_string().len() as u32;
+        add(mul(concatenated, pow(10, digits)), *number)
+    })
+}
+
+fn part2(times: &[Uint], distances: &[Uint]) -> Uint {
+    let time: Uint = concat_numbers(times);
+    let distance: Uint = concat_numbers(distances);
+    count_ways_to_win(time, distance)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = (Vec<Uint>, Vec<Uint>);
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        let mut input_lines = lines(input_str);
+        let times_line = input_lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input_str, "`Time: <numbers>`"))?;
+        let distances_line = input_lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input_str, "`Distance: <numbers>`"))?;
+        let times = get_numbers_from_line(&times_line)?;
+        let distances = get_numbers_from_line(&distances_line)?;
+        if distances.len() != times.len() {
+            return Err(distances_line.end_error(format!("{} distances", times.len())));
+        }
+        Ok((times, distances))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(&input.0, &input.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::check_property;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn part1_example() {
+        let (times, distances) = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&times, &distances), 288);
+    }
+
+    #[test]
+    fn part2_example() {
+        let (times, distances) = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&times, &distances), 71503);
+    }
+
+    #[test]
+    fn matches_linear_scan() {
+        check_property(200, |rng| {
+            let time = rng.range(0..=2000) as Uint;
+            let best = get_distance_for_hold_time(time / 2, time);
+            let record = rng.range(0..=best as i64 + 1) as Uint;
+            let scanned = (0..=time)
+                .filter(|hold_time| get_distance_for_hold_time(*hold_time, time) > record)
+                .count() as Uint;
+            assert_eq!(count_ways_to_win(time, record), scanned);
+        });
+    }
+}
@@ -0,0 +1,258 @@
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_common::{
+    number::{mul, sum, Uint},
+    parse::{lines, Line, ParseError},
+    repl::Command,
+    solution::Solution,
+};
+
+fn card_to_int(card: char, part2: bool) -> Option<u32> {
+    if let Some(v) = card.to_digit(10) {
+        return Some(v);
+    }
+    Some(match card {
+        'A' => 14,
+        'K' => 13,
+        'Q' => 12,
+        'J' => {
+            if part2 {
+                0
+            } else {
+                11
+            }
+        }
+
+        'T' => 10,
+        _ => return None,
+    })
+}
+
+#[derive(PartialEq, PartialOrd, Eq, Debug)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl Display for HandType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            HandType::HighCard => "high card",
+            HandType::OnePair => "one pair",
+            HandType::TwoPair => "two pair",
+            HandType::ThreeOfAKind => "three of a kind",
+            HandType::FullHouse => "full house",
+            HandType::FourOfAKind => "four of a kind",
+            HandType::FiveOfAKind => "five of a kind",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Hand {
+    cards: Vec<u32>,
+    hand_type: HandType,
+    bid: Uint,
+}
+
+impl Hand {
+    fn from_line(line: Line, part2: bool) -> Result<Self, ParseError> {
+        let (cards_str, bid_str) = line.split_once(line.text, " ")?;
+        let cards: Vec<u32> = cards_str
+            .char_indices()
+            .map(|(idx, c)| {
+                card_to_int(c, part2)
+                    .ok_or_else(|| line.error(&cards_str[idx..idx + c.len_utf8()], "a card"))
+            })
+            .collect::<Result<_, _>>()?;
+        if cards.len() != 5 {
+            return Err(line.error(cards_str, "a hand of 5 cards"));
+        }
+        let bid: Uint = line.parse(bid_str, "a bid")?;
+        let mut card_amounts: HashMap<u32, u32> = HashMap::from_iter(
+            cards
+                .clone()
+                .into_iter()
+                .map(|card| (card, cards.iter().filter(|c| **c == card).count() as u32)),
+        );
+        if part2 && card_amounts.contains_key(&0) {
+            let j_amount = *card_amounts.get(&0).unwrap();
+            let mut amounts_vec: Vec<(u32, u32)> = card_amounts.clone().into_iter().collect();
+            amounts_vec.sort_unstable_by(|(_, v1), (_, v2)| v2.cmp(v1));
+            let most_frequent = match amounts_vec[0] {
+                (0, _) => amounts_vec.get(1).unwrap_or(&(0, 0)).0,
+                _ => amounts_vec[0].0,
+            };
+            if most_frequent != 0 {
+                if let Some(x) = card_amounts.get_mut(&most_frequent) {
+                    *x += j_amount;
+                }
+                card_amounts.remove(&0);
+            }
+        }
+        let hand_type = match card_amounts.keys().len() {
+            1 => HandType::FiveOfAKind,
+            2 => {
+                if card_amounts.values().any(|x| x == &4) {
+                    HandType::FourOfAKind
+                } else {
+                    HandType::FullHouse
+                }
+            }
+            3 => {
+                if card_amounts.values().any(|x| x == &3) {
+                    HandType::ThreeOfAKind
+                } else {
+                    HandType::TwoPair
+                }
+            }
+            4 => HandType::OnePair,
+            5 => HandType::HighCard,
+            _ => unreachable!(),
+        };
+        Ok(Self {
+            cards,
+            hand_type,
+            bid,
+        })
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let cmp = self.hand_type.partial_cmp(&other.hand_type).unwrap();
+        if cmp != std::cmp::Ordering::Equal {
+            return cmp;
+        }
+        for i in 0..5 {
+            match self.cards[i].cmp(&other.cards[i]) {
+                std::cmp::Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+        std::cmp::Ordering::Equal
+    }
+}
+
+fn get_total_winnings(hands: &[Hand]) -> Uint {
+    let mut hands: Vec<_> = hands.iter().collect();
+    hands.sort_unstable();
+    sum((0..hands.len()).map(|i| mul(hands[i].bid, (i + 1) as Uint)))
+}
+
+/// The `classify <hand>` command of `aoc repl`, with and without `J` being a joker.
+fn classify_command(args: &str) -> Result<String, String> {
+    let line = format!("{args} 0");
+    let classify = |part2| {
+        Hand::from_line(
+            Line {
+                number: 1,
+                text: &line,
+            },
+            part2,
+        )
+        .map(|hand| hand.hand_type)
+        .map_err(|e| format!("expected {}, found {}", e.expected, e.found))
+    };
+    Ok(format!(
+        "{}, {} with jokers",
+        classify(false)?,
+        classify(true)?
+    ))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = (Vec<Hand>, Vec<Hand>);
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        Ok((
+            lines(input_str)
+                .map(|l| Hand::from_line(l, false))
+                .collect::<Result<_, _>>()?,
+            lines(input_str)
+                .map(|l| Hand::from_line(l, true))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        get_total_winnings(&input.0)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(get_total_winnings(&input.1))
+    }
+
+    fn commands() -> Vec<Command<Self::Input>> {
+        vec![Command {
+            name: "classify",
+            usage: "<hand>",
+            help: "the type of a hand like `KTJJT`",
+            run: |_, args| classify_command(args),
+            // the hands of the input, their cards spelled out again
+            words: |(hands, _)| {
+                hands
+                    .iter()
+                    .map(|hand| {
+                        hand.cards
+                            .iter()
+                            .map(|card| b"..23456789TJQKA"[*card as usize] as char)
+                            .collect()
+                    })
+                    .collect()
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn part1_example() {
+        let (hands, _) = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(get_total_winnings(&hands), 6440);
+    }
+
+    #[test]
+    fn part2_example() {
+        let (_, hands) = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(get_total_winnings(&hands), 5905);
+    }
+
+    #[test]
+    fn classifies_hands() {
+        assert_eq!(
+            classify_command("KTJJT"),
+            Ok("two pair, four of a kind with jokers".to_string())
+        );
+        assert_eq!(
+            classify_command("KTJ"),
+            Err("expected a hand of 5 cards, found `KTJ`".to_string())
+        );
+    }
+}
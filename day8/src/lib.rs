@@ -0,0 +1,241 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use aoc_common::{
+    combinator::{
+        blank_lines, delimited, labelled, lines_of, tag, take_while1, word, Input, Parser,
+    },
+    number::{add, mul, Uint},
+    parse::ParseError,
+    repl::Command,
+    solution::{unsolvable, Solution},
+};
+use num::integer::gcd;
+
+type Nodes = HashMap<String, (String, String)>;
+
+/// A node like `AAA = (BBB, CCC)`, with where its children are named.
+fn node<'a>() -> impl Parser<'a, (&'a str, [(&'a str, Input<'a>); 2])> {
+    let children = word()
+        .located()
+        .then_ignore(tag(", "))
+        .then(word().located());
+    labelled(word(), delimited(tag("("), children, tag(")")))
+        .map(|(name, (left, right))| (name, [left, right]))
+}
+
+fn parse(input_str: &str) -> Result<(String, Nodes), ParseError> {
+    let instructions = take_while1("`L` or `R`", |c| matches!(c, 'L' | 'R'));
+    let (instructions, lines) = instructions
+        .then_ignore(blank_lines())
+        .then(lines_of(node()))
+        .parse_all(input_str)?;
+    let defined: HashSet<&str> = lines.iter().map(|(name, _)| *name).collect();
+    for (child, at) in lines.iter().flat_map(|(_, children)| children) {
+        if !defined.contains(child) {
+            return Err(at.error("a defined node"));
+        }
+    }
+    let nodes = lines
+        .into_iter()
+        .map(|(name, [(left, _), (right, _)])| {
+            (name.to_string(), (left.to_string(), right.to_string()))
+        })
+        .collect();
+    Ok((instructions.to_string(), nodes))
+}
+
+/// Every child is a node, as `parse` checks.
+fn get_next_node<'a>(instruction: char, current_node: &str, nodes: &'a Nodes) -> &'a str {
+    let children = &nodes[current_node];
+    match instruction {
+        'L' => &children.0,
+        'R' => &children.1,
+        _ => unreachable!(),
+    }
+}
+
+/// The steps from `start` to the first node `is_end` accepts. Coming back to a node at the same
+/// instruction without getting there means the walk goes round forever, which is an error.
+fn steps(
+    instructions: &[char],
+    nodes: &Nodes,
+    start: &str,
+    is_end: fn(&str) -> bool,
+    end: &str,
+) -> Result<Uint, String> {
+    let mut seen = HashSet::new();
+    let mut current_node = start;
+    let mut idx = 0;
+    let mut steps: Uint = 0;
+    while !is_end(current_node) {
+        if !seen.insert((current_node, idx)) {
+            return Err(format!("`{start}` never reaches {end}"));
+        }
+        current_node = get_next_node(instructions[idx], current_node, nodes);
+        idx = (idx + 1) % instructions.len();
+        steps = add(steps, 1);
+    }
+    Ok(steps)
+}
+
+fn part1(instructions: &str, nodes: &Nodes) -> Result<Uint, String> {
+    for node in ["AAA", "ZZZ"] {
+        if !nodes.contains_key(node) {
+            return Err(format!("there is no node `{node}`"));
+        }
+    }
+    let instructions: Vec<char> = instructions.chars().collect();
+    steps(&instructions, nodes, "AAA", |node| node == "ZZZ", "`ZZZ`")
+}
+
+fn part2(instructions: &str, nodes: &Nodes) -> Result<Uint, String> {
+    let instructions: Vec<char> = instructions.chars().collect();
+    let mut start_nodes: Vec<&String> = nodes.keys().filter(|node| node.ends_with('A')).collect();
+    if start_nodes.is_empty() {
+        return Err("there is no node ending in `A`".to_string());
+    }
+    // the error names the same start node every time
+    start_nodes.sort();
+    start_nodes.into_iter().try_fold(1, |a, start_node| {
+        let b = steps(
+            &instructions,
+            nodes,
+            start_node,
+            |node| node.ends_with('Z'),
+            "a node ending in `Z`",
+        )?;
+        Ok(mul(a / gcd(a, b), b))
+    })
+}
+
+/// The `next <L|R> from <node>` command of `aoc repl`.
+fn next_command(nodes: &Nodes, args: &str) -> Result<String, String> {
+    let words: Vec<&str> = args.split_whitespace().collect();
+    let [instruction @ ("L" | "R"), "from", node] = words[..] else {
+        return Err("expected `<L|R> from <node>`".to_string());
+    };
+    if !nodes.contains_key(node) {
+        return Err(format!("there is no node `{node}`"));
+    }
+    let instruction = instruction.chars().next().unwrap();
+    Ok(get_next_node(instruction, node, nodes).to_string())
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = (String, Nodes);
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(&input.0, &input.1).unwrap_or_else(|e| unsolvable(e))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(&input.0, &input.1).unwrap_or_else(|e| unsolvable(e)))
+    }
+
+    fn commands() -> Vec<Command<Self::Input>> {
+        vec![Command {
+            name: "next",
+            usage: "<L|R> from <node>",
+            help: "the node an instruction leads to",
+            run: |(_, nodes), args| next_command(nodes, args),
+            words: |(_, nodes)| {
+                let mut words: Vec<String> = nodes.keys().cloned().collect();
+                words.sort();
+                words.extend(["L", "R", "from"].map(String::from));
+                words
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_2: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    #[test]
+    fn part1_example() {
+        let (instructions, nodes) = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&instructions, &nodes), Ok(2));
+    }
+
+    #[test]
+    fn part2_example() {
+        let (instructions, nodes) = Day8::parse(EXAMPLE_2).unwrap();
+        assert_eq!(part2(&instructions, &nodes), Ok(6));
+    }
+
+    #[test]
+    fn undefined_children_are_parse_errors() {
+        let input = "L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        assert_eq!(
+            Day8::parse(input).unwrap_err().to_string(),
+            "line 3, column 8: expected a defined node, found `BBB`"
+        );
+    }
+
+    #[test]
+    fn walks_that_never_arrive_are_errors() {
+        let (instructions, nodes) = Day8::parse(EXAMPLE_2).unwrap();
+        assert_eq!(
+            part1(&instructions, &nodes),
+            Err("there is no node `AAA`".to_string())
+        );
+        let (instructions, nodes) =
+            Day8::parse("RL\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert_eq!(
+            part1(&instructions, &nodes),
+            Err("`AAA` never reaches `ZZZ`".to_string())
+        );
+        assert_eq!(
+            part2(&instructions, &nodes),
+            Err("`AAA` never reaches a node ending in `Z`".to_string())
+        );
+    }
+
+    #[test]
+    fn next_node() {
+        let (_, nodes) = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(next_command(&nodes, "L from AAA"), Ok("BBB".to_string()));
+        assert_eq!(next_command(&nodes, "R  from CCC"), Ok("GGG".to_string()));
+        assert_eq!(
+            next_command(&nodes, "R from QQQ"),
+            Err("there is no node `QQQ`".to_string())
+        );
+        assert!(next_command(&nodes, "from AAA").is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    number::{mul, sub, sum, Int},
+    parse::{lines, ParseError},
+    solution::Solution,
+};
+
+fn parse(input_str: &str) -> Result<Vec<Vec<Int>>, ParseError> {
+    lines(input_str)
+        .map(|line| {
+            line.text
+                .split_whitespace()
+                .map(|num| line.parse(num, "a number"))
+                .collect()
+        })
+        .collect()
+}
+
+fn get_history(sequence: &[Int]) -> Vec<Vec<Int>> {
+    let mut history: Vec<Vec<Int>> = vec![];
+    let mut current_sequence = sequence.to_vec();
+    while current_sequence.iter().any(|x| *x != 0) {
+        history.push(current_sequence.clone());
+        current_sequence = current_sequence
+            .windows(2)
+            .map(|x| sub(x[1], x[0]))
+            .collect();
+    }
+    history
+}
+
+fn part1(parsed_input: &[Vec<Int>]) -> Int {
+    sum(parsed_input.iter().map(|sequence| {
+        sum(get_history(sequence)
+            .iter()
+            .map(|sequence| *sequence.last().unwrap()))
+    }))
+}
+
+fn part2(parsed_input: &[Vec<Int>]) -> Int {
+    sum(parsed_input.iter().map(|sequence| {
+        sum(get_history(sequence)
+            .iter()
+            .enumerate()
+            .map(|(idx, sequence)| {
+                mul(
+                    *sequence.first().unwrap(),
+                    if idx % 2 == 0 { 1 } else { -1 },
+                )
+            }))
+    }))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<Vec<Int>>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&Day9::parse(EXAMPLE).unwrap()), 114);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&Day9::parse(EXAMPLE).unwrap()), 2);
+    }
+}
@@ -0,0 +1,11 @@
+#![no_main]
+
+use aoc_common::solution::{Day, Part};
+use libfuzzer_sys::fuzz_target;
+
+const DAY: Day = Day::of::<day10::Day10>();
+
+fuzz_target!(|input: &str| {
+    // inputs the parser rejects are fine, panics and hangs while parsing or solving are not
+    let _ = DAY.run(input, &[Part::One, Part::Two]);
+});
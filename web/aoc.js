@@ -0,0 +1,82 @@
+// The days compiled to WebAssembly, see `Cargo.toml` for how to build `aoc_web.wasm`.
+//
+//     import { days, solve, draw } from "./aoc.js";
+//     solve(6, 1, input); // "288"
+
+const module = await WebAssembly.compileStreaming(fetch(new URL("aoc_web.wasm", import.meta.url)));
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+let wasm = instantiate();
+
+function instantiate() {
+  const exports = new WebAssembly.Instance(module).exports;
+  exports.init();
+  return exports;
+}
+
+function output() {
+  return new Uint8Array(wasm.memory.buffer, wasm.output_ptr(), wasm.output_len());
+}
+
+// Calls into the module with `input` in its input buffer. A panic leaves the module in an unknown
+// state, so it is replaced by a fresh instance after reporting the panic's message.
+function call(input, f) {
+  const bytes = encoder.encode(input);
+  // growing the buffer may grow the memory, which replaces `wasm.memory.buffer`
+  const ptr = wasm.input_buffer(bytes.length);
+  new Uint8Array(wasm.memory.buffer, ptr, bytes.length).set(bytes);
+  try {
+    return f();
+  } catch (e) {
+    if (!(e instanceof WebAssembly.RuntimeError)) {
+      throw e;
+    }
+    const message = decoder.decode(output());
+    wasm = instantiate();
+    throw new Error(message || `the solver crashed: ${e.message}`);
+  }
+}
+
+/** The numbers of the solved days. */
+export function days() {
+  wasm.days();
+  return Array.from(output());
+}
+
+/** The answer to `part` of `day` for `input`, throwing an `Error` if there is none. */
+export function solve(day, part, input) {
+  return call(input, () => {
+    const ok = wasm.solve(day, part);
+    const text = decoder.decode(output());
+    if (!ok) {
+      throw new Error(text);
+    }
+    return text;
+  });
+}
+
+/**
+ * The day's drawing for `input` as `{ width, height, frames }`, every frame being the RGBA pixels
+ * of one tile each, or `null` for days without a drawing.
+ */
+export function draw(day, input) {
+  return call(input, () => {
+    const count = wasm.draw(day);
+    const bytes = output();
+    if (count < 0) {
+      throw new Error(decoder.decode(bytes));
+    }
+    if (count === 0) {
+      return null;
+    }
+    const header = new DataView(bytes.buffer, bytes.byteOffset, 8);
+    const width = header.getUint32(0, true);
+    const height = header.getUint32(4, true);
+    const size = width * height * 4;
+    const frames = Array.from({ length: count }, (_, idx) =>
+      new Uint8ClampedArray(bytes.slice(8 + idx * size, 8 + (idx + 1) * size)),
+    );
+    return { width, height, frames };
+  });
+}
@@ -0,0 +1,228 @@
+//! The days compiled to WebAssembly. `aoc.js` wraps the small interface exported here, which
+//! passes strings and pixels through two buffers in the module's memory: JS writes the input to
+//! the one [`input_buffer`] returns and reads each call's result from [`output_ptr`].
+
+use std::{
+    cell::{Cell, RefCell},
+    panic,
+};
+
+use aoc_common::{
+    drawing::Rgb,
+    grid::Grid,
+    number::Overflow,
+    solution::{Day, Part, SolveError, Unsolvable},
+};
+
+#[path = "../../aoc/src/days.rs"]
+mod days;
+
+thread_local! {
+    static INPUT: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+    static OUTPUT: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+    /// The part being solved, which an overflow or an unsolvable part is reported for.
+    static PART: Cell<Option<Part>> = const { Cell::new(None) };
+}
+
+fn find(day: u8) -> Result<&'static Day, String> {
+    days::find(day).ok_or_else(|| format!("day {day} is not solved yet"))
+}
+
+/// Solves one part of a day.
+pub fn solve_part(day: u8, part: u8, input: &str) -> Result<String, String> {
+    let day = find(day)?;
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err(format!("there is no part {part}")),
+    };
+    PART.with(|p| p.set(Some(part)));
+    let answers = day.run(input, &[part]);
+    PART.with(|p| p.set(None));
+    let answers = answers.map_err(|e| e.to_string())?;
+    answers.parts[0]
+        .answer
+        .clone()
+        .ok_or_else(|| format!("part {part} of day {} is not solved yet", day.day))
+}
+
+/// The frames of a day's drawing, `None` for days without one.
+pub fn draw_frames(day: u8, input: &str) -> Result<Option<Vec<Grid<Rgb>>>, String> {
+    let drawing = find(day)?.draw(input).map_err(|e| e.to_string())?;
+    let Some(frames) = drawing.map(|d| d.into_frames()) else {
+        return Ok(None);
+    };
+    let first = frames.first().ok_or("the drawing has no frames")?;
+    if frames
+        .iter()
+        .any(|f| f.width() != first.width() || f.height() != first.height())
+    {
+        return Err("the frames of the drawing differ in size".to_string());
+    }
+    Ok(Some(frames))
+}
+
+/// The frames as their width and height, as little endian `u32`s, followed by every frame's
+/// pixels as RGBA bytes, ready to be put on a canvas.
+fn encode_frames(frames: &[Grid<Rgb>]) -> Vec<u8> {
+    let (width, height) = (frames[0].width() as u32, frames[0].height() as u32);
+    let mut bytes = [width.to_le_bytes(), height.to_le_bytes()].concat();
+    for color in frames.iter().flat_map(Grid::cells) {
+        bytes.extend([color.0, color.1, color.2, 255]);
+    }
+    bytes
+}
+
+fn input() -> String {
+    INPUT.with(|input| String::from_utf8_lossy(&input.borrow()).into_owned())
+}
+
+fn set_output(bytes: Vec<u8>) {
+    OUTPUT.with(|output| *output.borrow_mut() = bytes);
+}
+
+/// Makes a panic leave its message in the output, for JS to report after the module trapped.
+/// Overflows with the `checked` feature and unsolvable parts panic too, as panics cannot be
+/// caught here.
+#[no_mangle]
+pub extern "C" fn init() {
+    panic::set_hook(Box::new(|info| {
+        let part = PART.with(Cell::get);
+        let payload = info.payload();
+        let message = if let Some(overflow) = payload.downcast_ref::<Overflow>() {
+            SolveError::Overflow(part, *overflow).to_string()
+        } else if let Some(reason) = payload.downcast_ref::<Unsolvable>() {
+            SolveError::Unsolvable(part, reason.clone()).to_string()
+        } else {
+            info.to_string()
+        };
+        OUTPUT.with(|output| {
+            if let Ok(mut output) = output.try_borrow_mut() {
+                *output = message.into_bytes();
+            }
+        })
+    }));
+}
+
+/// Makes room for an input of `len` bytes, which JS then writes to the returned address.
+#[no_mangle]
+pub extern "C" fn input_buffer(len: usize) -> *mut u8 {
+    INPUT.with(|input| {
+        let mut input = input.borrow_mut();
+        input.clear();
+        input.resize(len, 0);
+        input.as_mut_ptr()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn output_ptr() -> *const u8 {
+    OUTPUT.with(|output| output.borrow().as_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn output_len() -> usize {
+    OUTPUT.with(|output| output.borrow().len())
+}
+
+/// Puts the numbers of the solved days in the output, one byte each.
+#[no_mangle]
+pub extern "C" fn days() {
+    set_output(days::DAYS.iter().map(|d| d.day).collect());
+}
+
+/// Solves one part of a day for the input, leaving the answer in the output. On failure the
+/// output is the error instead and `false` is returned.
+#[no_mangle]
+pub extern "C" fn solve(day: u8, part: u8) -> bool {
+    let (ok, text) = match solve_part(day, part, &input()) {
+        Ok(answer) => (true, answer),
+        Err(e) => (false, e),
+    };
+    set_output(text.into_bytes());
+    ok
+}
+
+/// Draws a day for the input, leaving the frames in the output as [`encode_frames`] lays them
+/// out. Returns the number of frames, which is `0` for days without a drawing, or `-1` with the
+/// error in the output.
+#[no_mangle]
+pub extern "C" fn draw(day: u8) -> i32 {
+    let (count, bytes) = match draw_frames(day, &input()) {
+        Ok(Some(frames)) => (frames.len() as i32, encode_frames(&frames)),
+        Ok(None) => (0, vec![]),
+        Err(e) => (-1, e.into_bytes()),
+    };
+    set_output(bytes);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output() -> String {
+        OUTPUT.with(|output| String::from_utf8(output.borrow().clone()).unwrap())
+    }
+
+    fn pass(input: &str) {
+        let ptr = input_buffer(input.len());
+        INPUT.with(|buffer| {
+            assert_eq!(buffer.borrow().as_ptr(), ptr);
+            buffer.borrow_mut().copy_from_slice(input.as_bytes());
+        });
+    }
+
+    #[test]
+    fn solves_through_buffers() {
+        pass("Time:      7  15   30\nDistance:  9  40  200\n");
+        assert!(solve(6, 1));
+        assert_eq!(output(), "288");
+        assert!(solve(6, 2));
+        assert_eq!(output(), "71503");
+        assert!(!solve(17, 1));
+        assert_eq!(output(), "day 17 is not solved yet");
+        pass("Time: 7\n");
+        assert!(!solve(6, 1));
+        assert!(output().starts_with("could not parse the input"));
+    }
+
+    #[test]
+    fn panics_report_errors() {
+        init();
+        PART.with(|p| p.set(Some(Part::Two)));
+        let trapped = panic::catch_unwind(|| panic::panic_any(Overflow { op: "add" }));
+        PART.with(|p| p.set(None));
+        let _ = panic::take_hook();
+        assert!(trapped.is_err());
+        assert_eq!(output(), "part 2: attempt to add with overflow");
+        init();
+        let trapped = panic::catch_unwind(|| {
+            panic::panic_any(Unsolvable("there is no node `AAA`".to_string()))
+        });
+        let _ = panic::take_hook();
+        assert!(trapped.is_err());
+        assert_eq!(output(), "there is no node `AAA`");
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn overflows_are_errors() {
+        // in either width, as another crate of the build may turn on `wide`
+        let bid = aoc_common::number::Uint::MAX;
+        pass(&format!("AAAAA {bid}\nKKKKK {bid}\n"));
+        assert!(!solve(7, 1));
+        assert_eq!(output(), "part 1: attempt to multiply with overflow");
+    }
+
+    #[test]
+    fn draws_frames() {
+        pass("R 6 (#70c710)\nD 5 (#0dc571)\nL 6 (#5713f0)\nU 5 (#d2c081)\n");
+        assert_eq!(draw(18), 1);
+        let bytes = OUTPUT.with(|output| output.borrow().clone());
+        assert_eq!(&bytes[..8], &[7, 0, 0, 0, 6, 0, 0, 0]);
+        assert_eq!(bytes.len(), 8 + 7 * 6 * 4);
+        pass("Time: 7\nDistance: 9\n");
+        assert_eq!(draw(6), 0);
+    }
+}